@@ -0,0 +1,135 @@
+use std::ffi::c_int;
+
+use ffi::{CPX_AT_LOWER, CPX_AT_UPPER, CPX_BASIC, CPX_FREE_SUPER};
+
+use crate::{
+    errors::{self, Result},
+    ConstraintId, ModelIds, ProblemToken, VariableId,
+};
+
+/// Status of a variable or of the slack of a constraint in a simplex basis.
+/// <https://www.ibm.com/docs/en/icos/22.1.1?topic=g-cpxxgetbase-cpxgetbase>
+///
+/// For constraints, `AtLower` and `AtUpper` refer to the bounds of the slack variable, and
+/// `AtUpper` can only occur for ranged constraints.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum BasisStatus {
+    Basic,
+    AtLower,
+    AtUpper,
+    FreeOrSuperbasic,
+}
+
+impl BasisStatus {
+    pub(crate) fn into_raw(self) -> c_int {
+        (match self {
+            BasisStatus::Basic => CPX_BASIC,
+            BasisStatus::AtLower => CPX_AT_LOWER,
+            BasisStatus::AtUpper => CPX_AT_UPPER,
+            BasisStatus::FreeOrSuperbasic => CPX_FREE_SUPER,
+        }) as c_int
+    }
+
+    pub(crate) fn from_raw(raw: c_int) -> Option<Self> {
+        match raw as u32 {
+            CPX_BASIC => Some(BasisStatus::Basic),
+            CPX_AT_LOWER => Some(BasisStatus::AtLower),
+            CPX_AT_UPPER => Some(BasisStatus::AtUpper),
+            CPX_FREE_SUPER => Some(BasisStatus::FreeOrSuperbasic),
+            _ => None,
+        }
+    }
+}
+
+/// A simplex basis, with the status of every variable and of the slack of every constraint
+/// of a problem.
+///
+/// A basis obtained from `Problem::basis` can be adapted to a modified problem through
+/// `push_variable` and `push_constraint`, then loaded back through `Problem::set_basis`.
+/// Only the ids of that problem, or of the model it has been loaded from, can be used to
+/// access the statuses.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Basis {
+    problem: ProblemToken,
+    model: Option<ModelIds>,
+    variables: Vec<BasisStatus>,
+    constraints: Vec<BasisStatus>,
+}
+
+impl Basis {
+    pub(crate) fn new(
+        problem: ProblemToken,
+        variables: Vec<BasisStatus>,
+        constraints: Vec<BasisStatus>,
+    ) -> Self {
+        Self {
+            problem,
+            model: None,
+            variables,
+            constraints,
+        }
+    }
+
+    pub(crate) fn with_model(mut self, model: Option<ModelIds>) -> Self {
+        self.model = model;
+        self
+    }
+
+    pub(crate) fn problem(&self) -> ProblemToken {
+        self.problem
+    }
+
+    pub fn variable_status(&self, v: VariableId) -> Result<BasisStatus> {
+        Ok(self.variables[self.variable_index(v)?])
+    }
+
+    pub fn constraint_status(&self, c: ConstraintId) -> Result<BasisStatus> {
+        Ok(self.constraints[self.constraint_index(c)?])
+    }
+
+    pub fn variable_statuses(&self) -> &[BasisStatus] {
+        &self.variables
+    }
+
+    pub fn constraint_statuses(&self) -> &[BasisStatus] {
+        &self.constraints
+    }
+
+    pub fn set_variable_status(&mut self, v: VariableId, status: BasisStatus) -> Result<()> {
+        let index = self.variable_index(v)?;
+        self.variables[index] = status;
+        Ok(())
+    }
+
+    pub fn set_constraint_status(&mut self, c: ConstraintId, status: BasisStatus) -> Result<()> {
+        let index = self.constraint_index(c)?;
+        self.constraints[index] = status;
+        Ok(())
+    }
+
+    /// Append the status of a variable added to the problem after the basis was retrieved.
+    pub fn push_variable(&mut self, status: BasisStatus) {
+        self.variables.push(status);
+    }
+
+    /// Append the status of a constraint added to the problem after the basis was retrieved.
+    pub fn push_constraint(&mut self, status: BasisStatus) {
+        self.constraints.push(status);
+    }
+
+    fn variable_index(&self, v: VariableId) -> Result<usize> {
+        let owned = v.problem == self.problem || self.model.is_some_and(|m| m.owns_variable(v));
+        if !owned || v.index >= self.variables.len() {
+            return Err(errors::Input::ForeignVariable { index: v.index }.into());
+        }
+        Ok(v.index)
+    }
+
+    fn constraint_index(&self, c: ConstraintId) -> Result<usize> {
+        let owned = c.problem == self.problem || self.model.is_some_and(|m| m.owns_constraint(c));
+        if !owned || c.index >= self.constraints.len() {
+            return Err(errors::Input::ForeignConstraint { index: c.index }.into());
+        }
+        Ok(c.index)
+    }
+}
@@ -0,0 +1,27 @@
+use crate::ConstraintId;
+
+/// A dual Farkas certificate proving the infeasibility of a linear problem.
+/// <https://www.ibm.com/docs/en/icos/22.1.1?topic=c-cpxxdualfarkas-cpxdualfarkas>
+///
+/// Only the constraints with a nonzero multiplier are part of the certificate.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FarkasCertificate {
+    multipliers: Vec<(ConstraintId, f64)>,
+    proof: f64,
+}
+
+impl FarkasCertificate {
+    pub(crate) fn new(multipliers: Vec<(ConstraintId, f64)>, proof: f64) -> Self {
+        Self { multipliers, proof }
+    }
+
+    /// The multipliers `y` of the constraints taking part in the proof of infeasibility
+    pub fn multipliers(&self) -> &[(ConstraintId, f64)] {
+        &self.multipliers
+    }
+
+    /// The amount `y'b - z'u` by which the certificate proves infeasibility, which is positive
+    pub fn proof(&self) -> f64 {
+        self.proof
+    }
+}
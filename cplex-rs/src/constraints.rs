@@ -1,33 +1,58 @@
+#[cfg(feature = "cplex")]
 use std::ffi::c_char;
 
 use crate::VariableId;
 
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
 pub enum ConstraintType {
     LessThanEq,
     Eq,
     GreaterThanEq,
+    /// `rhs <= expression <= rhs + range`, only through `Constraint::new_ranged`
+    Ranged,
 }
 
+#[cfg(feature = "cplex")]
 impl ConstraintType {
     pub(crate) fn into_raw(self) -> c_char {
         match self {
             ConstraintType::LessThanEq => 'L' as c_char,
             ConstraintType::Eq => 'E' as c_char,
             ConstraintType::GreaterThanEq => 'G' as c_char,
+            ConstraintType::Ranged => 'R' as c_char,
+        }
+    }
+
+    pub(crate) fn from_raw(raw: c_char) -> Option<Self> {
+        match raw as u8 {
+            b'L' => Some(ConstraintType::LessThanEq),
+            b'E' => Some(ConstraintType::Eq),
+            b'G' => Some(ConstraintType::GreaterThanEq),
+            b'R' => Some(ConstraintType::Ranged),
+            _ => None,
         }
     }
 }
 
+/// With the `serde` feature, a constraint can be serialized, and is deserialized either as
+/// part of a `Model`, which binds its variable ids, or as a `ConstraintData`.
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Constraint {
     weights: Vec<(VariableId, f64)>,
     type_: ConstraintType,
     rhs: f64,
+    range: Option<f64>,
     name: Option<String>,
 }
 
 impl Constraint {
+    /// Create a constraint `expression <ty> rhs`.
+    ///
+    /// `ConstraintType::Ranged` constraints need a range and must be created with
+    /// `Constraint::new_ranged`, otherwise they are rejected when added to a problem or model.
     pub fn new(
         ty: ConstraintType,
         rhs: f64,
@@ -38,6 +63,24 @@ impl Constraint {
             weights: vars,
             type_: ty,
             rhs,
+            range: None,
+            name,
+        }
+    }
+
+    /// Create a ranged constraint `lower <= expression <= upper`.
+    /// <https://www.ibm.com/docs/en/icos/22.1.1?topic=g-cpxxchgrngval-cpxchgrngval>
+    pub fn new_ranged(
+        lower: f64,
+        upper: f64,
+        name: Option<String>,
+        vars: Vec<(VariableId, f64)>,
+    ) -> Constraint {
+        Constraint {
+            weights: vars,
+            type_: ConstraintType::Ranged,
+            rhs: lower,
+            range: Some(upper - lower),
             name,
         }
     }
@@ -57,4 +100,46 @@ impl Constraint {
     pub fn type_(&self) -> ConstraintType {
         self.type_
     }
+
+    /// Width of the range of a `ConstraintType::Ranged` constraint, whose upper bound is
+    /// `rhs() + range`.
+    pub fn range(&self) -> Option<f64> {
+        self.range
+            .filter(|_| matches!(self.type_, ConstraintType::Ranged))
+    }
+}
+
+/// A deserialized constraint, whose variables are still plain indices. It becomes a
+/// `Constraint` once bound to the variables of a model with `Model::bind_constraint`.
+#[cfg(feature = "serde")]
+#[derive(Clone, Debug, serde::Deserialize)]
+#[serde(rename = "Constraint")]
+pub struct ConstraintData {
+    weights: Vec<(usize, f64)>,
+    type_: ConstraintType,
+    rhs: f64,
+    range: Option<f64>,
+    name: Option<String>,
+}
+
+#[cfg(feature = "serde")]
+impl ConstraintData {
+    /// Replace the variable indices with the ids returned by `bind`.
+    pub(crate) fn bind<F>(self, mut bind: F) -> crate::Result<Constraint>
+    where
+        F: FnMut(usize) -> crate::Result<VariableId>,
+    {
+        let weights = self
+            .weights
+            .into_iter()
+            .map(|(index, weight)| Ok((bind(index)?, weight)))
+            .collect::<crate::Result<_>>()?;
+        Ok(Constraint {
+            weights,
+            type_: self.type_,
+            rhs: self.rhs,
+            range: self.range,
+            name: self.name,
+        })
+    }
 }
@@ -3,13 +3,14 @@ use std::ffi::{c_void, CString};
 use crate::{
     errors::{self, Result},
     logging::{
-        get_trampoline, LoggingCallback, LoggingClosure, StreamType, DEFAULT_LOGGING_CLOSURE,
+        get_trampoline, LoggingCallback, LoggingClosure, MessageCapture, StreamType,
+        DEFAULT_LOGGING_CLOSURE,
     },
     parameters::{Parameter, ParameterValue},
 };
 use ffi::{
     cpxchannel, cpxenv, CPXaddfuncdest, CPXcloseCPLEX, CPXdelfuncdest, CPXgetchannels,
-    CPXopenCPLEX, CPXsetdblparam, CPXsetintparam, CPXsetlongparam, CPXsetstrparam,
+    CPXgetdettime, CPXopenCPLEX, CPXsetdblparam, CPXsetintparam, CPXsetlongparam, CPXsetstrparam,
 };
 use log::error;
 
@@ -73,6 +74,13 @@ impl Environment {
         }
     }
 
+    /// Get the current deterministic time stamp of the environment, in ticks.
+    pub fn deterministic_time(&self) -> Result<f64> {
+        let mut ticks = 0.0;
+        macros::cpx_env_result!(unsafe { CPXgetdettime(self.inner, &mut ticks) })?;
+        Ok(ticks)
+    }
+
     pub fn unset_logging_closure(&mut self, stream_type: StreamType) -> Result<()> {
         let channel = self.channel_from_stream_type(stream_type)?;
 
@@ -132,6 +140,57 @@ impl Environment {
         Ok(())
     }
 
+    /// Route all the CPLEX output channels to the `log` crate, using `cplex` as target.
+    ///
+    /// Messages from the `Results` and `Log` streams are logged at the `Info` level,
+    /// `Warning` messages at the `Warn` level and `Error` messages at the `Error` level.
+    pub fn route_to_log(&mut self) -> Result<()> {
+        self.route_to_log_with_target("cplex")
+    }
+
+    /// Route all the CPLEX output channels to the `log` crate, using the given target.
+    ///
+    /// See `route_to_log` for the mapping between streams and log levels.
+    pub fn route_to_log_with_target<S: Into<String>>(&mut self, target: S) -> Result<()> {
+        let target = target.into();
+        for stream_type in StreamType::ALL {
+            let target = target.clone();
+            let level = stream_type.log_level();
+            self.set_logging_closure(
+                stream_type,
+                move |msg| log::log!(target: &target, level, "{}", msg),
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Start capturing the messages emitted on all the output streams, without
+    /// affecting the logging closures already set on the environment.
+    pub(crate) fn capture_messages(&self) -> Result<MessageCapture> {
+        let mut capture = MessageCapture {
+            env: self.inner,
+            destinations: Vec::with_capacity(StreamType::ALL.len()),
+            messages: Default::default(),
+        };
+
+        for stream_type in StreamType::ALL {
+            let channel = self.channel_from_stream_type(stream_type)?;
+            let (closure, trampoline) = boxed_closure(capture.closure(stream_type));
+            let mut closure: LoggingClosure = closure;
+            macros::cpx_env_result!(unsafe {
+                CPXaddfuncdest(
+                    self.inner,
+                    channel,
+                    &mut *closure as *mut _ as *mut c_void,
+                    trampoline,
+                )
+            })?;
+            capture.destinations.push((channel, closure, trampoline));
+        }
+
+        Ok(capture)
+    }
+
     fn channel_from_stream_type(&self, stream_type: StreamType) -> Result<*mut cpxchannel> {
         let mut results_channel = std::ptr::null_mut();
         let mut warning_channel = std::ptr::null_mut();
@@ -170,3 +229,7 @@ impl Drop for Environment {
         }
     }
 }
+
+fn boxed_closure<F: Fn(&str) + Send + 'static>(closure: F) -> (Box<F>, LoggingCallback) {
+    (Box::new(closure), get_trampoline::<F>())
+}
@@ -1,20 +1,44 @@
-use std::{
-    ffi::{c_int, CString},
-    ops::Not,
-};
+use std::{ffi::c_int, fmt};
+#[cfg(feature = "cplex")]
+use std::{ffi::CString, ops::Not};
 
 pub type Result<T> = std::result::Result<T, Error>;
 
+#[cfg(feature = "cplex")]
 use ffi::{cpxenv, cpxlp, CPXgeterrorstring, CPXgetijdiv, CPXsolninfo, CPXMESSAGEBUFSIZE};
+#[cfg(feature = "cplex")]
 use log::error;
 use thiserror::Error;
 
+use crate::logging::LogMessage;
+
 #[derive(Error, Debug)]
 pub enum Error {
     #[error("Cplex error: {0}")]
     Cplex(#[from] Cplex),
     #[error("Input error: {0}")]
     Input(#[from] Input),
+    /// An error raised while reading or writing a file or a stream.
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+    /// An error raised while solving a problem with log capture enabled,
+    /// together with the messages emitted by CPLEX during the solve.
+    #[error("{error}")]
+    Logged {
+        error: Box<Error>,
+        log: Vec<LogMessage>,
+    },
+}
+
+impl Error {
+    /// The messages emitted by CPLEX during the solve that raised this error,
+    /// if log capture was enabled on the problem.
+    pub fn solve_log(&self) -> Option<&[LogMessage]> {
+        match self {
+            Self::Logged { log, .. } => Some(log),
+            _ => None,
+        }
+    }
 }
 
 #[derive(Error, Debug)]
@@ -25,6 +49,7 @@ pub enum Cplex {
     Other { code: c_int, message: String },
 }
 
+#[cfg(feature = "cplex")]
 impl Cplex {
     pub(crate) fn from_code(env: *const cpxenv, lp: *const cpxlp, code: c_int) -> Cplex {
         let mut buf = vec![0u8; CPXMESSAGEBUFSIZE as usize];
@@ -83,13 +108,95 @@ impl Cplex {
 }
 
 #[derive(Error, Debug)]
-#[error("Input error: {message}")]
-pub struct Input {
-    pub message: String,
+pub enum Input {
+    Other {
+        message: String,
+    },
+    /// A variable which cannot be added to the problem, or a value which cannot be given to
+    /// it. `index` is the index of the variable in the problem.
+    Variable {
+        index: usize,
+        name: String,
+        reason: InvalidValue,
+    },
+    /// A constraint which cannot be added to the problem. `index` is the index the
+    /// constraint would have had in the problem.
+    Constraint {
+        index: usize,
+        name: Option<String>,
+        reason: InvalidValue,
+    },
+    /// A variable id which does not belong to the problem, model or solution it has been
+    /// passed to, either because it comes from another one or because it is out of range.
+    ForeignVariable {
+        index: usize,
+    },
+    /// A constraint id which does not belong to the problem, model or solution it has been
+    /// passed to, either because it comes from another one or because it is out of range.
+    ForeignConstraint {
+        index: usize,
+    },
+    /// An objective id which does not belong to the solution it has been passed to, either
+    /// because it comes from another problem or because it is out of range.
+    ForeignObjective {
+        index: usize,
+    },
 }
 
 impl Input {
     pub(crate) fn from_message(message: String) -> Input {
-        Self { message }
+        Self::Other { message }
+    }
+
+    /// Description of the error, without the `Input error:` prefix.
+    pub fn message(&self) -> String {
+        match self {
+            Self::Other { message } => message.clone(),
+            Self::Variable {
+                index,
+                name,
+                reason,
+            } => format!("variable {index} ({name}): {reason}"),
+            Self::Constraint {
+                index,
+                name: Some(name),
+                reason,
+            } => format!("constraint {index} ({name}): {reason}"),
+            Self::Constraint {
+                index,
+                name: None,
+                reason,
+            } => format!("constraint {index}: {reason}"),
+            Self::ForeignVariable { index } => {
+                format!("variable id {index} does not belong to this problem")
+            }
+            Self::ForeignConstraint { index } => {
+                format!("constraint id {index} does not belong to this problem")
+            }
+            Self::ForeignObjective { index } => {
+                format!("objective id {index} does not belong to this problem")
+            }
+        }
+    }
+}
+
+impl fmt::Display for Input {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Input error: {}", self.message())
     }
 }
+
+/// The reason why a variable or a constraint has been rejected
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum InvalidValue {
+    #[error("{field} is NaN")]
+    NaN { field: String },
+    #[error("{field} {value} is beyond +/- constants::INFINITY")]
+    BeyondInfinity { field: String, value: f64 },
+    #[error("lower bound {lower} is greater than upper bound {upper}")]
+    BoundsOutOfOrder { lower: f64, upper: f64 },
+    #[error("bounds [{lower}, {upper}] of a binary variable are not within [0, 1]")]
+    BinaryBounds { lower: f64, upper: f64 },
+    #[error("ranged constraint has no range, use Constraint::new_ranged")]
+    MissingRange,
+}
@@ -0,0 +1,253 @@
+//! A model being read from an LP or MPS file, where variables and constraints are referenced
+//! by name and their attributes are known only once the whole file has been read.
+
+use std::collections::HashMap;
+
+use crate::{
+    constants::INFINITY, errors::Result, Constraint, ConstraintType, Model, ObjectiveType,
+    Variable, VariableType,
+};
+
+/// Number of significant digits of the numbers written to LP and MPS files
+const DIGITS: usize = 15;
+
+/// Format a number like the `%.15g` conversion of C, as CPLEX does when writing a model:
+/// `1e-12` rather than `0.000000000001`, and `0.3` rather than `0.30000000000000004`.
+pub(crate) fn format_number(value: f64) -> String {
+    if value == 0.0 {
+        // Also covers -0
+        return "0".to_owned();
+    }
+    // Round to the significant digits first, as the exponent may change, e.g. for 9.9999...
+    let scientific = format!("{value:.*e}", DIGITS - 1);
+    let (mantissa, exponent) = scientific
+        .split_once('e')
+        .expect("scientific notation has an exponent");
+    let exponent: i32 = exponent.parse().expect("exponent is an integer");
+
+    if (-4..DIGITS as i32).contains(&exponent) {
+        let decimals = (DIGITS as i32 - 1 - exponent) as usize;
+        trim_zeros(&format!("{value:.decimals$}")).to_owned()
+    } else {
+        let sign = if exponent < 0 { '-' } else { '+' };
+        format!("{}e{sign}{:02}", trim_zeros(mantissa), exponent.abs())
+    }
+}
+
+/// Remove the trailing zeros of the decimals of a number, and its decimal point if no
+/// decimals remain
+fn trim_zeros(number: &str) -> &str {
+    if number.contains('.') {
+        number.trim_end_matches('0').trim_end_matches('.')
+    } else {
+        number
+    }
+}
+
+/// Bring the values beyond +/- `constants::INFINITY`, like `1e30`, back to the value CPLEX
+/// uses for infinite bounds.
+pub(crate) fn clamp_infinity(value: f64) -> f64 {
+    value.clamp(-INFINITY, INFINITY)
+}
+
+#[derive(Debug)]
+pub(crate) struct Column {
+    pub(crate) name: String,
+    pub(crate) objective: f64,
+    pub(crate) lower: f64,
+    pub(crate) upper: f64,
+    pub(crate) type_: VariableType,
+}
+
+#[derive(Debug)]
+pub(crate) struct Row {
+    pub(crate) name: Option<String>,
+    pub(crate) type_: ConstraintType,
+    pub(crate) rhs: f64,
+    /// Width of the range of a `ConstraintType::Ranged` row
+    pub(crate) range: f64,
+    pub(crate) terms: Vec<(usize, f64)>,
+}
+
+#[derive(Debug)]
+pub(crate) struct FileModel {
+    pub(crate) name: String,
+    pub(crate) objective_type: ObjectiveType,
+    pub(crate) columns: Vec<Column>,
+    pub(crate) rows: Vec<Row>,
+    column_index: HashMap<String, usize>,
+    row_index: HashMap<String, usize>,
+}
+
+impl FileModel {
+    pub(crate) fn new() -> Self {
+        Self {
+            name: String::new(),
+            objective_type: ObjectiveType::Minimize,
+            columns: vec![],
+            rows: vec![],
+            column_index: HashMap::new(),
+            row_index: HashMap::new(),
+        }
+    }
+
+    /// Position of the column named `name`, which is created as a continuous variable with
+    /// the default `[0, +inf]` bounds on its first appearance, as CPLEX does.
+    pub(crate) fn column(&mut self, name: &str) -> usize {
+        if let Some(&index) = self.column_index.get(name) {
+            return index;
+        }
+        let index = self.columns.len();
+        self.columns.push(Column {
+            name: name.to_owned(),
+            objective: 0.0,
+            lower: 0.0,
+            upper: INFINITY,
+            type_: VariableType::Continuous,
+        });
+        self.column_index.insert(name.to_owned(), index);
+        index
+    }
+
+    pub(crate) fn find_column(&self, name: &str) -> Option<usize> {
+        self.column_index.get(name).copied()
+    }
+
+    pub(crate) fn add_row(&mut self, row: Row) -> usize {
+        let index = self.rows.len();
+        if let Some(name) = &row.name {
+            self.row_index.entry(name.clone()).or_insert(index);
+        }
+        self.rows.push(row);
+        index
+    }
+
+    pub(crate) fn find_row(&self, name: &str) -> Option<usize> {
+        self.row_index.get(name).copied()
+    }
+
+    pub(crate) fn into_model(self) -> Result<Model> {
+        let mut model = Model::new(&self.name);
+        model.set_objective_type(self.objective_type);
+
+        let ids = model.add_variables(
+            self.columns
+                .into_iter()
+                .map(|c| Variable::new(c.type_, c.objective, c.lower, c.upper, c.name))
+                .collect(),
+        )?;
+        model.add_constraints(
+            self.rows
+                .into_iter()
+                .map(|r| {
+                    let terms = r.terms.into_iter().map(|(j, v)| (ids[j], v)).collect();
+                    match r.type_ {
+                        ConstraintType::Ranged => {
+                            Constraint::new_ranged(r.rhs, r.rhs + r.range, r.name, terms)
+                        }
+                        ty => Constraint::new(ty, r.rhs, r.name, terms),
+                    }
+                })
+                .collect(),
+        )?;
+        Ok(model)
+    }
+}
+
+/// Models shared by the tests of the LP and MPS readers and writers.
+#[cfg(test)]
+pub(crate) mod fixtures {
+    use crate::{
+        constants::INFINITY, Constraint, ConstraintType, Model, ObjectiveType, Variable,
+        VariableType,
+    };
+
+    /// A small model with one variable of each common type, written out in full by the
+    /// format tests.
+    pub(crate) fn example_model() -> Model {
+        let mut model = Model::new("example");
+        let x = model
+            .add_variables(vec![
+                Variable::new(VariableType::Continuous, 1.0, 0.0, 40.0, "x1"),
+                Variable::new(VariableType::Integer, 2.0, 0.0, INFINITY, "x2"),
+                Variable::new(VariableType::Binary, -3.0, 0.0, 1.0, "x3"),
+                Variable::new(VariableType::Continuous, 0.0, -INFINITY, INFINITY, "x4"),
+            ])
+            .unwrap();
+        model
+            .add_constraints(vec![
+                Constraint::new(
+                    ConstraintType::LessThanEq,
+                    20.0,
+                    Some("c1".to_owned()),
+                    vec![(x[0], -1.0), (x[1], 1.0), (x[2], 1.0)],
+                ),
+                Constraint::new(
+                    ConstraintType::GreaterThanEq,
+                    -2.5,
+                    None,
+                    vec![(x[0], 1.0), (x[3], -3.5)],
+                ),
+            ])
+            .unwrap();
+        model.set_objective_type(ObjectiveType::Maximize);
+        model
+    }
+
+    /// A model with ranged rows, binaries, semi-continuous variables and free and fixed
+    /// bounds, which must survive a write and read cycle.
+    pub(crate) fn round_trip_model() -> Model {
+        let mut model = Model::new("round_trip");
+        let x = model
+            .add_variables(vec![
+                Variable::new(VariableType::SemiInteger, 1.0, 2.0, 8.0, "x1"),
+                Variable::new(VariableType::Continuous, -1.5, -INFINITY, 3.0, "x2"),
+                Variable::new(VariableType::Continuous, 0.5, 4.0, 4.0, "x3"),
+                Variable::new(VariableType::Binary, 2.0, 0.0, 1.0, "x4"),
+                Variable::new(VariableType::SemiContinuous, 0.25, 1.0, 6.0, "x5"),
+                Variable::new(VariableType::Continuous, 0.0, -INFINITY, INFINITY, "x6"),
+            ])
+            .unwrap();
+        model
+            .add_constraints(vec![
+                Constraint::new_ranged(-1.0, 3.0, None, vec![(x[0], 1.0), (x[1], 2.0)]),
+                Constraint::new(
+                    ConstraintType::Eq,
+                    5.0,
+                    None,
+                    vec![(x[2], 1.0), (x[3], 1.0)],
+                ),
+                Constraint::new(
+                    ConstraintType::GreaterThanEq,
+                    -2.5,
+                    Some("cover".to_owned()),
+                    vec![(x[4], 1e-3), (x[5], -1.0)],
+                ),
+            ])
+            .unwrap();
+        model
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn format_numbers() {
+        assert_eq!(format_number(0.0), "0");
+        assert_eq!(format_number(-0.0), "0");
+        assert_eq!(format_number(20.0), "20");
+        assert_eq!(format_number(-2.5), "-2.5");
+        assert_eq!(format_number(0.1 + 0.2), "0.3");
+        assert_eq!(format_number(1.0 / 3.0), "0.333333333333333");
+        assert_eq!(format_number(1e-4), "0.0001");
+        assert_eq!(format_number(1e-5), "1e-05");
+        assert_eq!(format_number(1e-12), "1e-12");
+        assert_eq!(format_number(-1.5e-7), "-1.5e-07");
+        assert_eq!(format_number(123456789012345.0), "123456789012345");
+        assert_eq!(format_number(1e15), "1e+15");
+        assert_eq!(format_number(INFINITY), "1e+20");
+        assert_eq!(format_number(0.999_999_999_999_999_9), "1");
+    }
+}
@@ -1,7 +1,12 @@
 //! Safe rust bindings to the CPLEX solver API.
 //!
+//! The CPLEX bindings are enabled by the default `cplex` feature. Without it, the crate
+//! only provides the pure rust `Model`, `Solution` and file formats, and builds without a
+//! CPLEX installation.
+//!
 //! # Example
 //! ```
+//! # #[cfg(feature = "cplex")] {
 //! use cplex_rs::*;
 //!
 //! let env = Environment::new().unwrap();
@@ -19,36 +24,110 @@
 //!
 //! assert_eq!(solution.variable_value(v0), 0.3);
 //! assert_eq!(solution.variable_value(v1), 0.7);
+//! # }
 //! ```
 
+#[cfg(feature = "cplex")]
+mod basis;
+#[cfg(feature = "cplex")]
+mod certificates;
 pub mod constants;
 mod constraints;
+#[cfg(feature = "cplex")]
 mod environment;
 pub mod errors;
+mod file_model;
 pub mod logging;
+pub mod lp_file;
+#[cfg(feature = "cplex")]
+mod matrix;
+#[cfg(feature = "cplex")]
+mod mip_starts;
+mod model;
+pub mod mps_file;
+mod names;
+#[cfg(feature = "cplex")]
+mod objectives;
+#[cfg(feature = "cplex")]
 pub mod parameters;
+#[cfg(feature = "cplex")]
+mod progress;
+#[cfg(feature = "cplex")]
+mod quality;
+#[cfg(feature = "cplex")]
+mod sensitivity;
+pub mod sol_file;
 mod solution;
+mod validation;
 mod variables;
 
+#[cfg(feature = "cplex")]
+pub use basis::*;
+#[cfg(feature = "cplex")]
+pub use certificates::*;
 pub use constraints::*;
+#[cfg(feature = "cplex")]
 pub use environment::*;
 pub use errors::{Error, Result};
+#[cfg(feature = "cplex")]
 pub use ffi;
+#[cfg(feature = "cplex")]
 use ffi::{
-    cpxlp, CPX_STAT_INForUNBD, CPXaddmipstarts, CPXaddrows, CPXchgobj, CPXchgobjsen,
-    CPXchgprobtype, CPXcreateprob, CPXfreeprob, CPXgetobjval, CPXgetstat, CPXgetx, CPXlpopt,
-    CPXmipopt, CPXnewcols, CPXwriteprob, CPXMIP_UNBOUNDED, CPXPROB_LP, CPXPROB_MILP, CPX_MAX,
-    CPX_MIN, CPX_STAT_INFEASIBLE, CPX_STAT_UNBOUNDED,
+    cpxlp, CPXCENVptr, CPXCLPptr, CPX_STAT_INForUNBD, CPX_STAT_MULTIOBJ_INForUNBD, CPXaddmipstarts,
+    CPXaddrows, CPXboundsa, CPXcallbacksetfunc, CPXchgobj, CPXchgobjsen, CPXchgprobtype,
+    CPXchgrngval, CPXcopybase, CPXcopylp, CPXcreateprob, CPXdelmipstarts, CPXdualfarkas,
+    CPXfreeprob, CPXgetbaritcnt, CPXgetbase, CPXgetbestobjval, CPXgetcolname, CPXgetcols,
+    CPXgetctype, CPXgetdblquality, CPXgetitcnt, CPXgetlb, CPXgetlongannotationindex,
+    CPXgetmipitcnt, CPXgetmiprelgap, CPXgetmipstartname, CPXgetmipstarts, CPXgetnodecnt,
+    CPXgetnumcols, CPXgetnumlongannotations, CPXgetnummipstarts, CPXgetnumnz, CPXgetnumrows,
+    CPXgetobj, CPXgetobjsen, CPXgetobjval, CPXgetprobtype, CPXgetray, CPXgetrhs, CPXgetrngval,
+    CPXgetrowname, CPXgetrows, CPXgetsense, CPXgetstat, CPXgetub, CPXgetx, CPXlpopt, CPXmbasewrite,
+    CPXmipopt, CPXmultiobjgetobjval, CPXmultiobjopt, CPXmultiobjsetobj, CPXnewcols,
+    CPXnewlongannotation, CPXobjsa, CPXreadcopybase, CPXreadcopymipstarts, CPXrhssa,
+    CPXsetlongannotations, CPXsetnumobjs, CPXsolninfo, CPXsolwrite, CPXwritemipstarts,
+    CPXwriteprob, CPXERR_NEGATIVE_SURPLUS, CPXERR_NOT_FOR_MIP, CPXERR_NOT_MIP,
+    CPXERR_NO_BASIC_SOLN, CPXERR_NO_BASIS, CPXERR_NO_DUAL_SOLN, CPXERR_NO_KAPPASTATS,
+    CPXERR_NO_NAMES, CPXMIP_UNBOUNDED, CPXPROB_LP, CPXPROB_MILP, CPX_ANNOTATIONOBJ_COL,
+    CPX_BASIC_SOLN, CPX_BENDERS_ANNOTATION, CPX_BENDERS_MASTERVALUE,
+    CPX_CALLBACKCONTEXT_GLOBAL_PROGRESS, CPX_KAPPA, CPX_KAPPA_ATTENTION, CPX_KAPPA_ILLPOSED,
+    CPX_KAPPA_MAX, CPX_KAPPA_STABLE, CPX_KAPPA_SUSPICIOUS, CPX_KAPPA_UNSTABLE, CPX_MAX,
+    CPX_MAX_DUAL_INFEAS, CPX_MAX_INT_INFEAS, CPX_MAX_PRIMAL_INFEAS, CPX_MAX_SCALED_DUAL_INFEAS,
+    CPX_MAX_SCALED_PRIMAL_INFEAS, CPX_MIN, CPX_STAT_INFEASIBLE, CPX_STAT_MULTIOBJ_INFEASIBLE,
+    CPX_STAT_MULTIOBJ_UNBOUNDED, CPX_STAT_UNBOUNDED,
 };
+#[cfg(feature = "cplex")]
 use log::debug;
+#[cfg(feature = "cplex")]
+pub use matrix::SparseMatrix;
+#[cfg(feature = "cplex")]
+pub use mip_starts::*;
+pub use model::Model;
+pub use names::DuplicateNamePolicy;
+#[cfg(feature = "cplex")]
+use names::NameIndex;
+#[cfg(feature = "cplex")]
+pub use objectives::*;
+#[cfg(feature = "cplex")]
+pub use progress::MipProgress;
+#[cfg(feature = "cplex")]
+use progress::{progress_callback, ProgressHandler};
+#[cfg(feature = "cplex")]
+pub use quality::*;
+#[cfg(feature = "cplex")]
+pub use sensitivity::*;
 pub use solution::*;
 pub use variables::*;
 
+use std::sync::atomic::{AtomicU64, Ordering};
+#[cfg(feature = "cplex")]
 use std::{
-    ffi::{c_int, CString},
-    time::Instant,
+    borrow::{Borrow, BorrowMut},
+    ffi::{c_char, c_int, c_void, CStr, CString},
+    sync::mpsc,
+    time::{Duration, Instant},
 };
 
+#[cfg(feature = "cplex")]
 mod macros {
     macro_rules! cpx_lp_result {
     ( unsafe { $func:ident ( $env:expr, $lp:expr $(, $b:expr)* $(,)? ) } ) => {
@@ -66,42 +145,221 @@ mod macros {
     pub(super) use cpx_lp_result;
 }
 
+/// A token identifying a problem instance, used to brand the ids it hands out
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+struct ProblemToken(u64);
+
+impl ProblemToken {
+    fn new() -> Self {
+        static NEXT: AtomicU64 = AtomicU64::new(0);
+        Self(NEXT.fetch_add(1, Ordering::Relaxed))
+    }
+}
+
+/// The ids of the model a problem has been loaded from: those of the variables and
+/// constraints the model had at that time remain valid on the problem and its solutions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct ModelIds {
+    token: ProblemToken,
+    variables: usize,
+    constraints: usize,
+}
+
+impl ModelIds {
+    fn owns_variable(&self, id: VariableId) -> bool {
+        self.token == id.problem && id.index < self.variables
+    }
+
+    #[cfg(feature = "cplex")]
+    fn owns_constraint(&self, id: ConstraintId) -> bool {
+        self.token == id.problem && id.index < self.constraints
+    }
+}
+
 /// A variable identifier, unique with respect to a given problem instance
+///
+/// Passing the id of a variable to another problem, or to a solution of another problem,
+/// results in an `errors::Input` error.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
-pub struct VariableId(usize);
+pub struct VariableId {
+    index: usize,
+    problem: ProblemToken,
+}
 
 impl VariableId {
     pub fn into_inner(self) -> usize {
-        self.0
+        self.index
+    }
+}
+
+/// Serialized as its index. Ids cannot be deserialized on their own, as they would not be
+/// bound to any problem: they are rebound when deserializing a `Model`, and can otherwise be
+/// looked up again with `variable_by_name` or `variable_by_index`.
+#[cfg(feature = "serde")]
+impl serde::Serialize for VariableId {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.index.serialize(serializer)
     }
 }
 
 /// A constraint identifier, unique with respect to a given problem instance
+///
+/// Passing the id of a constraint to another problem results in an `errors::Input` error.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
-pub struct ConstraintId(usize);
+pub struct ConstraintId {
+    index: usize,
+    problem: ProblemToken,
+}
 
 impl ConstraintId {
+    pub fn into_inner(self) -> usize {
+        self.index
+    }
+}
+
+/// Serialized as its index. As for `VariableId`, ids cannot be deserialized on their own.
+#[cfg(feature = "serde")]
+impl serde::Serialize for ConstraintId {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.index.serialize(serializer)
+    }
+}
+
+/// A MIP start identifier, unique with respect to a given problem instance
+///
+/// Deleting MIP starts shifts the identifiers of the following ones.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MipStartId(usize);
+
+impl MipStartId {
     pub fn into_inner(self) -> usize {
         self.0
     }
 }
 
+/// An objective identifier, unique with respect to a given problem instance
+///
+/// Passing the id of an objective to a solution of another problem results in an
+/// `errors::Input` error.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ObjectiveId {
+    index: usize,
+    problem: ProblemToken,
+}
+
+impl ObjectiveId {
+    pub fn into_inner(self) -> usize {
+        self.index
+    }
+}
+
+/// A long annotation identifier, unique with respect to a given problem instance
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AnnotationId(usize);
+
+impl AnnotationId {
+    pub fn into_inner(self) -> usize {
+        self.0
+    }
+}
+
+/// The partition a variable belongs to in a Benders decomposition.
+///
+/// Subproblems are numbered starting from 0.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum BendersPartition {
+    Master,
+    Subproblem(u32),
+}
+
+#[cfg(feature = "cplex")]
+impl BendersPartition {
+    fn into_raw(self) -> i64 {
+        match self {
+            BendersPartition::Master => CPX_BENDERS_MASTERVALUE as i64,
+            BendersPartition::Subproblem(n) => CPX_BENDERS_MASTERVALUE as i64 + 1 + n as i64,
+        }
+    }
+}
+
 /// A CPLEX problem instance
-pub struct Problem {
+///
+/// Only available with the default `cplex` feature.
+///
+/// A problem can either own its `Environment` (the default), or share it with other
+/// problems through a reference, an `Rc` or an `Arc`. In all cases, the problem is freed
+/// before the environment it has been created from is closed.
+///
+/// # Example
+/// ```
+/// use std::rc::Rc;
+/// use cplex_rs::*;
+///
+/// let env = Rc::new(Environment::new().unwrap());
+/// let first = Problem::new(Rc::clone(&env), "first").unwrap();
+/// let second = Problem::new(Rc::clone(&env), "second").unwrap();
+/// ```
+#[cfg(feature = "cplex")]
+pub struct Problem<E: Borrow<Environment> = Environment> {
     inner: *mut cpxlp,
-    env: Environment,
+    env: E,
+    name: String,
+    token: ProblemToken,
+    model: Option<ModelIds>,
+    capture_log: bool,
     variables: Vec<Variable>,
     constraints: Vec<Constraint>,
+    variable_index: NameIndex,
+    constraint_index: NameIndex,
+    duplicate_names: DuplicateNamePolicy,
+    objectives: Vec<Objective>,
+    progress_handler: Option<Box<ProgressHandler>>,
 }
 
-unsafe impl Send for Problem {}
+#[cfg(feature = "cplex")]
+unsafe impl<E: Borrow<Environment> + Send> Send for Problem<E> {}
+
+#[cfg(feature = "cplex")]
+type NameGetter = unsafe extern "C" fn(
+    CPXCENVptr,
+    CPXCLPptr,
+    *mut *mut c_char,
+    *mut c_char,
+    c_int,
+    *mut c_int,
+    c_int,
+    c_int,
+) -> c_int;
+#[cfg(feature = "cplex")]
+type DoubleGetter = unsafe extern "C" fn(CPXCENVptr, CPXCLPptr, *mut f64, c_int, c_int) -> c_int;
+#[cfg(feature = "cplex")]
+type SparseGetter = unsafe extern "C" fn(
+    CPXCENVptr,
+    CPXCLPptr,
+    *mut c_int,
+    *mut c_int,
+    *mut c_int,
+    *mut f64,
+    c_int,
+    *mut c_int,
+    c_int,
+    c_int,
+) -> c_int;
 
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ObjectiveType {
     Maximize,
     Minimize,
 }
 
+#[cfg(feature = "cplex")]
 impl ObjectiveType {
     fn into_raw(self) -> c_int {
         match self {
@@ -117,6 +375,7 @@ pub enum ProblemType {
     MixedInteger,
 }
 
+#[cfg(feature = "cplex")]
 impl ProblemType {
     fn into_raw(self) -> c_int {
         match self {
@@ -126,48 +385,209 @@ impl ProblemType {
     }
 }
 
-impl Problem {
+#[cfg(feature = "cplex")]
+impl<E: Borrow<Environment>> Problem<E> {
     /// Create a new CPLEX problem from a CPLEX environmant
-    pub fn new<S>(env: Environment, name: S) -> Result<Self>
+    ///
+    /// The environment can be passed by value, or shared through a reference, an `Rc` or an `Arc`.
+    pub fn new<S>(env: E, name: S) -> Result<Self>
     where
         S: AsRef<str>,
     {
         let mut status = 0;
-        let name =
+        let c_name =
             CString::new(name.as_ref()).map_err(|e| errors::Input::from_message(e.to_string()))?;
-        let inner = unsafe { CPXcreateprob(env.inner, &mut status, name.as_ptr()) };
+        let inner = unsafe { CPXcreateprob(env.borrow().inner, &mut status, c_name.as_ptr()) };
         if inner.is_null() {
-            Err(errors::Cplex::from_code(env.inner, std::ptr::null(), status).into())
+            Err(errors::Cplex::from_code(env.borrow().inner, std::ptr::null(), status).into())
         } else {
             Ok(Problem {
                 inner,
                 env,
+                name: name.as_ref().to_owned(),
+                token: ProblemToken::new(),
+                model: None,
+                capture_log: false,
                 variables: vec![],
                 constraints: vec![],
+                variable_index: NameIndex::default(),
+                constraint_index: NameIndex::default(),
+                duplicate_names: DuplicateNamePolicy::default(),
+                objectives: vec![],
+                progress_handler: None,
             })
         }
     }
 
-    /// Get a mutable reference to the environment of the problem.
-    pub fn env_mut(&mut self) -> &mut Environment {
-        &mut self.env
+    /// Create a new CPLEX problem holding the variables, constraints and objective type of
+    /// a `Model`.
+    ///
+    /// The ids handed out by the model for its current variables and constraints can be
+    /// used with the problem and its solutions. The problem hands out ids of its own, so
+    /// the ids of the variables and constraints added later to the model or to another
+    /// problem loaded from it are rejected.
+    pub fn from_model(env: E, model: &Model) -> Result<Self> {
+        let mut problem = Self::new(env, model.name())?;
+        problem.model = Some(model.ids());
+        let mut problem = problem.set_objective_type(model.objective_type())?;
+
+        // Names have already been checked by the model
+        problem.set_duplicate_name_policy(DuplicateNamePolicy::Allow);
+        problem.add_variables(model.variables().to_vec())?;
+        if !model.constraints().is_empty() {
+            problem.add_constraints(model.constraints().to_vec())?;
+        }
+        problem.set_duplicate_name_policy(model.duplicate_name_policy());
+        Ok(problem)
     }
 
     /// Get a reference to the environment of the problem.
     pub fn env(&self) -> &Environment {
-        &self.env
+        self.env.borrow()
+    }
+
+    /// Get the name of the problem.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Enable or disable the capture of the CPLEX messages emitted while solving the problem.
+    ///
+    /// When enabled, the messages of all the output streams are returned, timestamped, on
+    /// the `Solution` or, if the solve fails, on an `errors::Error::Logged` error. The
+    /// logging closures set on the environment keep receiving the messages.
+    ///
+    /// The capture listens on the output channels of the environment, so when several
+    /// problems share an environment, messages emitted by the other problems during the
+    /// solve are captured too. Give the problem an environment of its own to only capture
+    /// its messages.
+    pub fn set_log_capture(&mut self, capture: bool) {
+        self.capture_log = capture;
+    }
+
+    /// Set a closure receiving the progress of the MIP optimization.
+    ///
+    /// The closure is invoked by CPLEX from the global progress callback while
+    /// `CPXmipopt` runs, so it should return quickly. Setting a new closure replaces
+    /// the previous one.
+    pub fn set_progress_handler<F>(&mut self, handler: F) -> Result<()>
+    where
+        F: FnMut(MipProgress) + Send + 'static,
+    {
+        let handler = Box::new(ProgressHandler::new(handler));
+        macros::cpx_lp_result!(unsafe {
+            CPXcallbacksetfunc(
+                self.env().inner,
+                self.inner,
+                CPX_CALLBACKCONTEXT_GLOBAL_PROGRESS as i64,
+                Some(progress_callback),
+                &*handler as *const ProgressHandler as *mut c_void,
+            )
+        })?;
+        self.progress_handler = Some(handler);
+        Ok(())
+    }
+
+    /// Get a channel receiving the progress of the MIP optimization.
+    ///
+    /// See `set_progress_handler`. Updates are silently dropped once the receiver is dropped.
+    pub fn progress_channel(&mut self) -> Result<mpsc::Receiver<MipProgress>> {
+        let (sender, receiver) = mpsc::channel();
+        self.set_progress_handler(move |progress| {
+            let _ = sender.send(progress);
+        })?;
+        Ok(receiver)
+    }
+
+    fn variable_id(&self, index: usize) -> VariableId {
+        VariableId {
+            index,
+            problem: self.token,
+        }
+    }
+
+    fn constraint_id(&self, index: usize) -> ConstraintId {
+        ConstraintId {
+            index,
+            problem: self.token,
+        }
+    }
+
+    fn check_variables<'a, I>(&self, ids: I) -> Result<()>
+    where
+        I: IntoIterator<Item = &'a VariableId>,
+    {
+        for &id in ids {
+            if !self.owns_variable(id) {
+                return Err(errors::Input::ForeignVariable { index: id.index }.into());
+            }
+        }
+        Ok(())
+    }
+
+    /// Check that the variables belong to the problem and that the values given to them,
+    /// described by `field`, are finite.
+    fn check_values<'a, I>(&self, field: &str, values: I) -> Result<()>
+    where
+        I: IntoIterator<Item = &'a (VariableId, f64)>,
+    {
+        for &(id, value) in values {
+            self.check_variables([&id])?;
+            validation::validate_value(id.index, &self.variables[id.index], field, value)?;
+        }
+        Ok(())
+    }
+
+    fn owns_variable(&self, id: VariableId) -> bool {
+        id.index < self.variables.len()
+            && (self.token == id.problem || self.model.is_some_and(|m| m.owns_variable(id)))
+    }
+
+    /// Set the policy applied when adding a variable or a constraint with a name already used
+    /// in the problem. Default: `DuplicateNamePolicy::Warn`.
+    pub fn set_duplicate_name_policy(&mut self, policy: DuplicateNamePolicy) {
+        self.duplicate_names = policy;
+    }
+
+    /// Get the id of the first variable added to the problem with the given name.
+    pub fn variable_by_name(&self, name: &str) -> Option<VariableId> {
+        self.variable_index
+            .get(name)
+            .map(|index| self.variable_id(index))
+    }
+
+    /// Get the id of the first constraint added to the problem with the given name.
+    pub fn constraint_by_name(&self, name: &str) -> Option<ConstraintId> {
+        self.constraint_index
+            .get(name)
+            .map(|index| self.constraint_id(index))
+    }
+
+    /// Get the id of the variable at position `index`, in the order the variables have been
+    /// added, e.g. to rebind the index of a serialized id.
+    pub fn variable_by_index(&self, index: usize) -> Option<VariableId> {
+        (index < self.variables.len()).then(|| self.variable_id(index))
+    }
+
+    /// Get the id of the constraint at position `index`, in the order the constraints have
+    /// been added.
+    pub fn constraint_by_index(&self, index: usize) -> Option<ConstraintId> {
+        (index < self.constraints.len()).then(|| self.constraint_id(index))
     }
 
     /// Add a variable to the problem.
     ///
     /// The id for the Variable is returned.
     pub fn add_variable(&mut self, var: Variable) -> Result<VariableId> {
+        validation::validate_variable(self.variables.len(), &var)?;
+        self.variable_index
+            .check([var.name()], self.duplicate_names, "variable")?;
         let name = CString::new(var.name().as_bytes())
             .map_err(|e| errors::Input::from_message(e.to_string()))?;
 
         macros::cpx_lp_result!(unsafe {
             CPXnewcols(
-                self.env.inner,
+                self.env().inner,
                 self.inner,
                 1,
                 &var.weight(),
@@ -179,14 +599,23 @@ impl Problem {
         })?;
 
         let index = self.variables.len();
+        self.variable_index.insert(var.name(), index);
         self.variables.push(var);
-        Ok(VariableId(index))
+        Ok(self.variable_id(index))
     }
 
     /// Add an array of variables to the problem.
     ///
     /// The id for the variables are returned, in the same order they have been given in the input.
     pub fn add_variables(&mut self, vars: Vec<Variable>) -> Result<Vec<VariableId>> {
+        for (idx, var) in vars.iter().enumerate() {
+            validation::validate_variable(self.variables.len() + idx, var)?;
+        }
+        self.variable_index.check(
+            vars.iter().map(|v| v.name()),
+            self.duplicate_names,
+            "variable",
+        )?;
         let names = vars
             .iter()
             .map(|v| {
@@ -210,7 +639,7 @@ impl Problem {
 
         macros::cpx_lp_result!(unsafe {
             CPXnewcols(
-                self.env.inner,
+                self.env().inner,
                 self.inner,
                 vars.len() as i32,
                 objs.as_ptr(),
@@ -224,21 +653,219 @@ impl Problem {
         let indices: Vec<VariableId> = vars
             .iter()
             .enumerate()
-            .map(|(idx, _)| VariableId(idx + self.variables.len()))
+            .map(|(idx, _)| self.variable_id(idx + self.variables.len()))
             .collect();
+        for (var, id) in vars.iter().zip(&indices) {
+            self.variable_index.insert(var.name(), id.index);
+        }
         self.variables.extend(vars);
         Ok(indices)
     }
 
+    /// Load a whole linear problem at once from a column-wise (CSC) constraint matrix.
+    ///
+    /// The `k`-th column of `matrix` holds the coefficients of the `k`-th variable, whose
+    /// objective coefficient and bounds are `objective[k]`, `lower_bounds[k]` and
+    /// `upper_bounds[k]`. The `i`-th constraint has right hand side `rhs[i]` and type
+    /// `types[i]`, which cannot be `ConstraintType::Ranged` as ranges cannot be given here.
+    /// The variables are continuous, and neither variables nor constraints are named. The
+    /// objective type of the problem is kept.
+    ///
+    /// The problem must not have any variable or constraint yet. The ids of the variables
+    /// and of the constraints are returned.
+    pub fn load_matrix(
+        &mut self,
+        matrix: &SparseMatrix<'_>,
+        objective: &[f64],
+        lower_bounds: &[f64],
+        upper_bounds: &[f64],
+        rhs: &[f64],
+        types: &[ConstraintType],
+    ) -> Result<(Vec<VariableId>, Vec<ConstraintId>)> {
+        if !self.variables.is_empty() || !self.constraints.is_empty() {
+            return Err(errors::Input::from_message(
+                "Called load_matrix on a problem which already has variables or constraints"
+                    .to_owned(),
+            )
+            .into());
+        }
+        let num_cols = matrix.outer_len();
+        let num_rows = rhs.len();
+        if objective.len() != num_cols
+            || lower_bounds.len() != num_cols
+            || upper_bounds.len() != num_cols
+            || types.len() != num_rows
+        {
+            return Err(errors::Input::from_message(format!(
+                "load_matrix expects {num_cols} objective coefficients and bounds and {num_rows} constraint types, got {}, {}, {} and {}",
+                objective.len(),
+                lower_bounds.len(),
+                upper_bounds.len(),
+                types.len(),
+            ))
+            .into());
+        }
+        if matrix.max_index().is_some_and(|i| i as usize >= num_rows) {
+            return Err(errors::Input::from_message(format!(
+                "load_matrix matrix refers to constraints beyond the {num_rows} given ones"
+            ))
+            .into());
+        }
+
+        let variables = (0..num_cols)
+            .map(|j| {
+                Variable::new(
+                    VariableType::Continuous,
+                    objective[j],
+                    lower_bounds[j],
+                    upper_bounds[j],
+                    String::new(),
+                )
+            })
+            .collect::<Vec<_>>();
+        let constraints = rhs
+            .iter()
+            .zip(types)
+            .map(|(&rhs, &ty)| Constraint::new(ty, rhs, None, vec![]))
+            .collect::<Vec<_>>();
+        for (j, var) in variables.iter().enumerate() {
+            validation::validate_variable(j, var)?;
+        }
+        for (i, constraint) in constraints.iter().enumerate() {
+            validation::validate_constraint(i, constraint, |_| true)?;
+        }
+        for (j, w) in matrix.starts().windows(2).enumerate() {
+            for k in w[0] as usize..w[1] as usize {
+                validation::validate_coefficient(
+                    matrix.indices()[k] as usize,
+                    j,
+                    matrix.values()[k],
+                )?;
+            }
+        }
+
+        let senses = types.iter().map(|t| t.into_raw()).collect::<Vec<_>>();
+        let counts = matrix.counts();
+        let objsense = unsafe { CPXgetobjsen(self.env().inner, self.inner) };
+        macros::cpx_lp_result!(unsafe {
+            CPXcopylp(
+                self.env().inner,
+                self.inner,
+                num_cols as c_int,
+                num_rows as c_int,
+                objsense,
+                objective.as_ptr(),
+                rhs.as_ptr(),
+                senses.as_ptr(),
+                matrix.starts().as_ptr(),
+                counts.as_ptr(),
+                matrix.indices().as_ptr(),
+                matrix.values().as_ptr(),
+                lower_bounds.as_ptr(),
+                upper_bounds.as_ptr(),
+                std::ptr::null(),
+            )
+        })?;
+
+        self.variables = variables;
+        self.constraints = constraints;
+        Ok((
+            (0..num_cols).map(|j| self.variable_id(j)).collect(),
+            (0..num_rows).map(|i| self.constraint_id(i)).collect(),
+        ))
+    }
+
+    /// Add constraints in bulk from a row-wise (CSR) matrix.
+    ///
+    /// The `i`-th row of `matrix` holds the coefficients of the `i`-th new constraint, with
+    /// right hand side `rhs[i]` and type `types[i]`. Column indices refer to the variables
+    /// of the problem, in the order they have been added. The constraints are not named.
+    /// `ConstraintType::Ranged` is rejected, as ranges cannot be given here.
+    ///
+    /// The ids of the new constraints are returned.
+    pub fn add_rows(
+        &mut self,
+        matrix: &SparseMatrix<'_>,
+        rhs: &[f64],
+        types: &[ConstraintType],
+    ) -> Result<Vec<ConstraintId>> {
+        let num_rows = matrix.outer_len();
+        if rhs.len() != num_rows || types.len() != num_rows {
+            return Err(errors::Input::from_message(format!(
+                "add_rows expects {num_rows} right hand sides and constraint types, got {} and {}",
+                rhs.len(),
+                types.len(),
+            ))
+            .into());
+        }
+        if matrix
+            .max_index()
+            .is_some_and(|j| j as usize >= self.variables.len())
+        {
+            return Err(errors::Input::from_message(format!(
+                "add_rows matrix refers to variables beyond the {} of the problem",
+                self.variables.len()
+            ))
+            .into());
+        }
+
+        let first = self.constraints.len();
+        let constraints = rhs
+            .iter()
+            .zip(types)
+            .map(|(&rhs, &ty)| Constraint::new(ty, rhs, None, vec![]))
+            .collect::<Vec<_>>();
+        for (i, constraint) in constraints.iter().enumerate() {
+            validation::validate_constraint(first + i, constraint, |_| true)?;
+        }
+        for (i, w) in matrix.starts().windows(2).enumerate() {
+            for k in w[0] as usize..w[1] as usize {
+                validation::validate_coefficient(
+                    first + i,
+                    matrix.indices()[k] as usize,
+                    matrix.values()[k],
+                )?;
+            }
+        }
+
+        let senses = types.iter().map(|t| t.into_raw()).collect::<Vec<_>>();
+        macros::cpx_lp_result!(unsafe {
+            CPXaddrows(
+                self.env().inner,
+                self.inner,
+                0,
+                num_rows as c_int,
+                matrix.nnz() as c_int,
+                rhs.as_ptr(),
+                senses.as_ptr(),
+                matrix.starts().as_ptr(),
+                matrix.indices().as_ptr(),
+                matrix.values().as_ptr(),
+                std::ptr::null_mut(),
+                std::ptr::null_mut(),
+            )
+        })?;
+
+        self.constraints.extend(constraints);
+        Ok((first..first + num_rows)
+            .map(|i| self.constraint_id(i))
+            .collect())
+    }
+
     /// Add a constraint to the problem.
     ///
     /// The id for the constraint is returned.
     pub fn add_constraint(&mut self, constraint: Constraint) -> Result<ConstraintId> {
+        validation::validate_constraint(self.constraints.len(), &constraint, |v| {
+            self.owns_variable(v)
+        })?;
+        self.constraint_index
+            .check(constraint.name(), self.duplicate_names, "constraint")?;
         let (ind, val): (Vec<c_int>, Vec<f64>) = constraint
             .weights()
             .iter()
             .filter(|(_, weight)| *weight != 0.0)
-            .map(|(var_id, weight)| (var_id.0 as c_int, weight))
+            .map(|(var_id, weight)| (var_id.index as c_int, weight))
             .unzip();
         let nz = val.len() as c_int;
         let name = constraint
@@ -249,7 +876,7 @@ impl Problem {
             .transpose()?;
         macros::cpx_lp_result!(unsafe {
             CPXaddrows(
-                self.env.inner,
+                self.env().inner,
                 self.inner,
                 0,
                 1,
@@ -266,10 +893,14 @@ impl Problem {
                     .unwrap_or(std::ptr::null()) as *mut _),
             )
         })?;
+        self.set_ranges(self.constraints.len(), std::slice::from_ref(&constraint))?;
 
         let index = self.constraints.len();
+        if let Some(name) = constraint.name() {
+            self.constraint_index.insert(name, index);
+        }
         self.constraints.push(constraint);
-        Ok(ConstraintId(index))
+        Ok(self.constraint_id(index))
     }
 
     /// Add an array of constraints to the problem.
@@ -282,6 +913,16 @@ impl Problem {
             )
             .into());
         }
+        for (idx, c) in con.iter().enumerate() {
+            validation::validate_constraint(self.constraints.len() + idx, c, |v| {
+                self.owns_variable(v)
+            })?;
+        }
+        self.constraint_index.check(
+            con.iter().filter_map(|c| c.name()),
+            self.duplicate_names,
+            "constraint",
+        )?;
         let beg = std::iter::once(0)
             .chain(con[..con.len() - 1].iter().map(|c| c.weights().len()))
             .scan(0, |state, x| {
@@ -294,7 +935,7 @@ impl Problem {
             .iter()
             .flat_map(|c| c.weights().iter())
             .filter(|(_, weight)| *weight != 0.0)
-            .map(|(var_id, weight)| (var_id.0 as c_int, weight))
+            .map(|(var_id, weight)| (var_id.index as c_int, weight))
             .unzip();
 
         let nz = val.len() as c_int;
@@ -324,7 +965,7 @@ impl Problem {
 
         macros::cpx_lp_result!(unsafe {
             CPXaddrows(
-                self.env.inner,
+                self.env().inner,
                 self.inner,
                 0,
                 con.len() as i32,
@@ -338,26 +979,54 @@ impl Problem {
                 name_ptrs.as_mut_ptr(),
             )
         })?;
+        self.set_ranges(self.constraints.len(), &con)?;
 
-        let indices = con
+        let indices: Vec<ConstraintId> = con
             .iter()
             .enumerate()
-            .map(|(idx, _)| ConstraintId(idx + self.constraints.len()))
+            .map(|(idx, _)| self.constraint_id(idx + self.constraints.len()))
             .collect();
+        for (c, id) in con.iter().zip(&indices) {
+            if let Some(name) = c.name() {
+                self.constraint_index.insert(name, id.index);
+            }
+        }
         self.constraints.extend(con);
         Ok(indices)
     }
 
+    /// Set the range of the ranged constraints among `con`, added from position `first`.
+    fn set_ranges(&self, first: usize, con: &[Constraint]) -> Result<()> {
+        let (ind, val): (Vec<c_int>, Vec<f64>) = con
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, c)| Some(((first + idx) as c_int, c.range()?)))
+            .unzip();
+        if ind.is_empty() {
+            return Ok(());
+        }
+        macros::cpx_lp_result!(unsafe {
+            CPXchgrngval(
+                self.env().inner,
+                self.inner,
+                ind.len() as c_int,
+                ind.as_ptr(),
+                val.as_ptr(),
+            )
+        })
+    }
+
     /// Set the objective coefficients.
     pub fn set_objective(self, ty: ObjectiveType, obj: Vec<(VariableId, f64)>) -> Result<Self> {
+        self.check_values("objective coefficient", &obj)?;
         let (ind, val): (Vec<c_int>, Vec<f64>) = obj
             .into_iter()
-            .map(|(var_id, weight)| (var_id.0 as c_int, weight))
+            .map(|(var_id, weight)| (var_id.index as c_int, weight))
             .unzip();
 
         macros::cpx_lp_result!(unsafe {
             CPXchgobj(
-                self.env.inner,
+                self.env().inner,
                 self.inner,
                 ind.len() as c_int,
                 ind.as_ptr(),
@@ -368,9 +1037,72 @@ impl Problem {
         self.set_objective_type(ty)
     }
 
+    /// Add an objective to a multi-objective problem.
+    ///
+    /// The first objective added replaces the one defined through the variable weights
+    /// or `set_objective`. As soon as at least one objective has been added, the problem
+    /// is solved with the CPLEX multi-objective optimizer, and the value of each
+    /// objective can be retrieved from the `Solution`.
+    ///
+    /// The id for the objective is returned.
+    pub fn add_objective(&mut self, objective: Objective) -> Result<ObjectiveId> {
+        self.check_values("objective coefficient", objective.coefficients())?;
+        let priority = c_int::try_from(objective.priority()).map_err(|_| {
+            errors::Input::from_message(format!(
+                "Objective priority {} is greater than {}",
+                objective.priority(),
+                c_int::MAX
+            ))
+        })?;
+        let (ind, val): (Vec<c_int>, Vec<f64>) = objective
+            .coefficients()
+            .iter()
+            .filter(|(_, weight)| *weight != 0.0)
+            .map(|(var_id, weight)| (var_id.index as c_int, weight))
+            .unzip();
+        let name = objective
+            .name()
+            .map(|n| {
+                CString::new(n.as_bytes()).map_err(|e| errors::Input::from_message(e.to_string()))
+            })
+            .transpose()?;
+
+        let index = self.objectives.len();
+        macros::cpx_lp_result!(unsafe {
+            CPXsetnumobjs(self.env().inner, self.inner, index as c_int + 1)
+        })?;
+
+        macros::cpx_lp_result!(unsafe {
+            CPXmultiobjsetobj(
+                self.env().inner,
+                self.inner,
+                index as c_int,
+                val.len() as c_int,
+                ind.as_ptr(),
+                val.as_ptr(),
+                0.0,
+                objective.weight(),
+                priority,
+                objective.abs_tolerance(),
+                objective.rel_tolerance(),
+                name.as_ref()
+                    .map(|n| n.as_ptr())
+                    .unwrap_or(std::ptr::null()),
+            )
+        })?;
+
+        self.objectives.push(objective);
+        Ok(ObjectiveId {
+            index,
+            problem: self.token,
+        })
+    }
+
     /// Change the objective type. Default: `ObjectiveType::Minimize`.
     pub fn set_objective_type(self, ty: ObjectiveType) -> Result<Self> {
-        macros::cpx_lp_result!(unsafe { CPXchgobjsen(self.env.inner, self.inner, ty.into_raw()) })?;
+        macros::cpx_lp_result!(unsafe {
+            CPXchgobjsen(self.env().inner, self.inner, ty.into_raw())
+        })?;
         Ok(self)
     }
 
@@ -383,59 +1115,1040 @@ impl Problem {
             CString::new(name.as_ref()).map_err(|e| errors::Input::from_message(e.to_string()))?;
 
         macros::cpx_lp_result!(unsafe {
-            CPXwriteprob(self.env.inner, self.inner, name.as_ptr(), std::ptr::null())
+            CPXwriteprob(
+                self.env().inner,
+                self.inner,
+                name.as_ptr(),
+                std::ptr::null(),
+            )
         })
     }
 
-    /// Add an initial solution to the problem.
+    /// Add an array of MIP starts to the problem.
     ///
-    /// `vars` is an array of indices (i.e. the result of `prob.add_variable`) and `values` are
-    /// their values.
-    pub fn add_initial_soln(&mut self, vars: &[VariableId], values: &[f64]) -> Result<()> {
-        if values.len() != vars.len() {
-            return Err(errors::Input::from_message(
-                "number of solution variables and values does not match".to_string(),
-            )
-            .into());
-        }
-        let vars = vars.iter().map(|&u| u.0 as c_int).collect::<Vec<_>>();
+    /// The id for the MIP starts are returned, in the same order they have been given in the input.
+    pub fn add_mip_starts(&mut self, starts: Vec<MipStart>) -> Result<Vec<MipStartId>> {
+        let beg = starts
+            .iter()
+            .scan(0, |state, s| {
+                let beg = *state;
+                *state += s.values().len() as c_int;
+                Some(beg)
+            })
+            .collect::<Vec<_>>();
 
-        macros::cpx_lp_result!(unsafe {
-            CPXaddmipstarts(
-                self.env.inner,
-                self.inner,
-                1,
-                vars.len() as c_int,
-                &0,
-                vars.as_ptr(),
-                values.as_ptr(),
-                &0,
-                &mut std::ptr::null_mut(),
-            )
-        })
-    }
+        self.check_values("MIP start value", starts.iter().flat_map(|s| s.values()))?;
+        let (ind, val): (Vec<c_int>, Vec<f64>) = starts
+            .iter()
+            .flat_map(|s| s.values().iter())
+            .map(|&(var_id, value)| (var_id.index as c_int, value))
+            .unzip();
 
-    /// Solve the Problem, returning a `Solution` object with the
-    /// result.
-    pub fn solve_as(self, pt: ProblemType) -> Result<Solution> {
-        macros::cpx_lp_result!(unsafe {
-            CPXchgprobtype(self.env.inner, self.inner, pt.into_raw())
-        })?;
+        let efforts = starts
+            .iter()
+            .map(|s| s.effort().into_raw())
+            .collect::<Vec<_>>();
 
-        let start_optim = Instant::now();
+        let names = starts
+            .iter()
+            .map(|s| {
+                s.name()
+                    .map(|n| {
+                        CString::new(n.as_bytes())
+                            .map_err(|e| errors::Input::from_message(e.to_string()).into())
+                    })
+                    .transpose()
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut name_ptrs = names
+            .iter()
+            .map(|n| {
+                n.as_ref()
+                    .map(|n| n.as_ptr())
+                    .unwrap_or(std::ptr::null_mut()) as *mut _
+            })
+            .collect::<Vec<_>>();
+
+        let first = unsafe { CPXgetnummipstarts(self.env().inner, self.inner) } as usize;
+
+        macros::cpx_lp_result!(unsafe {
+            CPXaddmipstarts(
+                self.env().inner,
+                self.inner,
+                starts.len() as c_int,
+                val.len() as c_int,
+                beg.as_ptr(),
+                ind.as_ptr(),
+                val.as_ptr(),
+                efforts.as_ptr(),
+                name_ptrs.as_mut_ptr(),
+            )
+        })?;
+
+        Ok((first..first + starts.len()).map(MipStartId).collect())
+    }
+
+    /// Get the MIP starts currently stored in the problem.
+    pub fn mip_starts(&self) -> Result<Vec<MipStart>> {
+        let count = unsafe { CPXgetnummipstarts(self.env().inner, self.inner) };
+        if count == 0 {
+            return Ok(vec![]);
+        }
+
+        let mut nzcnt = 0;
+        let mut surplus = 0;
+        let mut beg = vec![0; count as usize];
+        let mut efforts = vec![0; count as usize];
+        let status = unsafe {
+            CPXgetmipstarts(
+                self.env().inner,
+                self.inner,
+                &mut nzcnt,
+                beg.as_mut_ptr(),
+                std::ptr::null_mut(),
+                std::ptr::null_mut(),
+                efforts.as_mut_ptr(),
+                0,
+                &mut surplus,
+                0,
+                count - 1,
+            )
+        };
+        if status != 0 && status != CPXERR_NEGATIVE_SURPLUS as c_int {
+            return Err(errors::Cplex::from_code(self.env().inner, self.inner, status).into());
+        }
+
+        let space = -surplus;
+        let mut ind = vec![0; space as usize];
+        let mut val = vec![0.0; space as usize];
+        macros::cpx_lp_result!(unsafe {
+            CPXgetmipstarts(
+                self.env().inner,
+                self.inner,
+                &mut nzcnt,
+                beg.as_mut_ptr(),
+                ind.as_mut_ptr(),
+                val.as_mut_ptr(),
+                efforts.as_mut_ptr(),
+                space,
+                &mut surplus,
+                0,
+                count - 1,
+            )
+        })?;
+
+        let names = self.names(CPXgetmipstartname, count)?;
+
+        let ends = beg
+            .iter()
+            .skip(1)
+            .copied()
+            .chain(std::iter::once(nzcnt))
+            .collect::<Vec<_>>();
+
+        beg.iter()
+            .zip(ends)
+            .zip(efforts)
+            .zip(names)
+            .map(|(((&beg, end), effort), name)| {
+                let effort = MipStartEffort::from_raw(effort).ok_or_else(|| {
+                    errors::Input::from_message(format!("Unknown MIP start effort level {effort}"))
+                })?;
+                let values = (beg as usize..end as usize)
+                    .map(|i| (self.variable_id(ind[i] as usize), val[i]))
+                    .collect();
+                Ok(MipStart::new(effort, name, values))
+            })
+            .collect()
+    }
+
+    /// Delete a MIP start from the problem.
+    ///
+    /// The ids of the MIP starts following the deleted one are shifted down by one.
+    pub fn delete_mip_start(&mut self, id: MipStartId) -> Result<()> {
+        macros::cpx_lp_result!(unsafe {
+            CPXdelmipstarts(self.env().inner, self.inner, id.0 as c_int, id.0 as c_int)
+        })
+    }
+
+    /// Delete all the MIP starts from the problem.
+    pub fn delete_all_mip_starts(&mut self) -> Result<()> {
+        let count = unsafe { CPXgetnummipstarts(self.env().inner, self.inner) };
+        if count == 0 {
+            return Ok(());
+        }
+        macros::cpx_lp_result!(unsafe {
+            CPXdelmipstarts(self.env().inner, self.inner, 0, count - 1)
+        })
+    }
+
+    /// Write the MIP starts of the problem to a file named `name`, in the CPLEX MST format.
+    pub fn write_mip_starts<S>(&self, name: S) -> Result<()>
+    where
+        S: AsRef<str>,
+    {
+        let name =
+            CString::new(name.as_ref()).map_err(|e| errors::Input::from_message(e.to_string()))?;
+        let count = unsafe { CPXgetnummipstarts(self.env().inner, self.inner) };
+        if count == 0 {
+            return Err(errors::Input::from_message(
+                "Called write_mip_starts with 0 MIP starts".to_owned(),
+            )
+            .into());
+        }
+
+        macros::cpx_lp_result!(unsafe {
+            CPXwritemipstarts(self.env().inner, self.inner, name.as_ptr(), 0, count - 1)
+        })
+    }
+
+    /// Read the MIP starts from a file named `name`, in the CPLEX MST format, and add them
+    /// to the problem.
+    pub fn read_mip_starts<S>(&mut self, name: S) -> Result<()>
+    where
+        S: AsRef<str>,
+    {
+        let name =
+            CString::new(name.as_ref()).map_err(|e| errors::Input::from_message(e.to_string()))?;
+
+        macros::cpx_lp_result!(unsafe {
+            CPXreadcopymipstarts(self.env().inner, self.inner, name.as_ptr())
+        })
+    }
+
+    /// Write the current solution of the problem to a file named `name`, in the CPLEX SOL format.
+    ///
+    /// The problem must have been solved through `solve_as`. The file can be read back through
+    /// `read_mip_starts`, or parsed without CPLEX through `sol_file::read`.
+    pub fn write_solution<S>(&self, name: S) -> Result<()>
+    where
+        S: AsRef<str>,
+    {
+        let name =
+            CString::new(name.as_ref()).map_err(|e| errors::Input::from_message(e.to_string()))?;
+
+        macros::cpx_lp_result!(unsafe { CPXsolwrite(self.env().inner, self.inner, name.as_ptr()) })
+    }
+
+    /// Get the number of variables of the problem, as stored by CPLEX.
+    pub fn num_variables(&self) -> usize {
+        unsafe { CPXgetnumcols(self.env().inner, self.inner) as usize }
+    }
+
+    /// Get the number of constraints of the problem, as stored by CPLEX.
+    pub fn num_constraints(&self) -> usize {
+        unsafe { CPXgetnumrows(self.env().inner, self.inner) as usize }
+    }
+
+    /// Get the number of nonzero coefficients in the constraint matrix of the problem,
+    /// as stored by CPLEX.
+    pub fn num_nonzeros(&self) -> usize {
+        unsafe { CPXgetnumnz(self.env().inner, self.inner) as usize }
+    }
+
+    /// Get the names of the variables of the problem, as stored by CPLEX.
+    pub fn variable_names(&self) -> Result<Vec<Option<String>>> {
+        self.names(CPXgetcolname, self.num_variables() as c_int)
+    }
+
+    /// Get the names of the constraints of the problem, as stored by CPLEX.
+    pub fn constraint_names(&self) -> Result<Vec<Option<String>>> {
+        self.names(CPXgetrowname, self.num_constraints() as c_int)
+    }
+
+    /// Get the objective coefficients of the variables of the problem, as stored by CPLEX.
+    pub fn objective_coefficients(&self) -> Result<Vec<f64>> {
+        self.doubles(CPXgetobj, self.num_variables())
+    }
+
+    /// Get the lower and upper bounds of the variables of the problem, as stored by CPLEX.
+    pub fn variable_bounds(&self) -> Result<Vec<(f64, f64)>> {
+        let lb = self.doubles(CPXgetlb, self.num_variables())?;
+        let ub = self.doubles(CPXgetub, self.num_variables())?;
+        Ok(lb.into_iter().zip(ub).collect())
+    }
+
+    /// Get the types of the variables of the problem, as stored by CPLEX.
+    ///
+    /// All the variables of a continuous problem are `VariableType::Continuous`.
+    pub fn variable_types(&self) -> Result<Vec<VariableType>> {
+        let count = self.num_variables();
+        if count == 0 {
+            return Ok(vec![]);
+        }
+
+        let mut ctype = vec![0 as c_char; count];
+        let status = unsafe {
+            CPXgetctype(
+                self.env().inner,
+                self.inner,
+                ctype.as_mut_ptr(),
+                0,
+                count as c_int - 1,
+            )
+        };
+        if status == CPXERR_NOT_MIP as c_int {
+            return Ok(vec![VariableType::Continuous; count]);
+        } else if status != 0 {
+            return Err(errors::Cplex::from_code(self.env().inner, self.inner, status).into());
+        }
+
+        ctype
+            .into_iter()
+            .map(|t| {
+                VariableType::from_raw(t as u8).ok_or_else(|| {
+                    errors::Input::from_message(format!(
+                        "Unknown variable type {}",
+                        t as u8 as char
+                    ))
+                    .into()
+                })
+            })
+            .collect()
+    }
+
+    /// Get the types of the constraints of the problem, as stored by CPLEX.
+    pub fn constraint_types(&self) -> Result<Vec<ConstraintType>> {
+        let count = self.num_constraints();
+        if count == 0 {
+            return Ok(vec![]);
+        }
+
+        let mut sense = vec![0 as c_char; count];
+        macros::cpx_lp_result!(unsafe {
+            CPXgetsense(
+                self.env().inner,
+                self.inner,
+                sense.as_mut_ptr(),
+                0,
+                count as c_int - 1,
+            )
+        })?;
+
+        sense
+            .into_iter()
+            .map(|s| {
+                ConstraintType::from_raw(s).ok_or_else(|| {
+                    errors::Input::from_message(format!(
+                        "Unknown constraint type {}",
+                        s as u8 as char
+                    ))
+                    .into()
+                })
+            })
+            .collect()
+    }
+
+    /// Get the right hand sides of the constraints of the problem, as stored by CPLEX.
+    pub fn constraint_rhs(&self) -> Result<Vec<f64>> {
+        self.doubles(CPXgetrhs, self.num_constraints())
+    }
+
+    /// Get the ranges of the constraints of the problem, as stored by CPLEX.
+    ///
+    /// The range is 0 for constraints which are not `ConstraintType::Ranged`.
+    pub fn constraint_ranges(&self) -> Result<Vec<f64>> {
+        self.doubles(CPXgetrngval, self.num_constraints())
+    }
+
+    /// Get the nonzero coefficients of every constraint of the problem, as stored by CPLEX.
+    pub fn rows(&self) -> Result<Vec<Vec<(VariableId, f64)>>> {
+        Ok(self
+            .sparse_vectors(CPXgetrows, self.num_constraints() as c_int)?
+            .into_iter()
+            .map(|row| {
+                row.into_iter()
+                    .map(|(j, v)| (self.variable_id(j), v))
+                    .collect()
+            })
+            .collect())
+    }
+
+    /// Get the nonzero coefficients of every variable of the problem, as stored by CPLEX.
+    pub fn columns(&self) -> Result<Vec<Vec<(ConstraintId, f64)>>> {
+        Ok(self
+            .sparse_vectors(CPXgetcols, self.num_variables() as c_int)?
+            .into_iter()
+            .map(|col| {
+                col.into_iter()
+                    .map(|(i, v)| (self.constraint_id(i), v))
+                    .collect()
+            })
+            .collect())
+    }
+
+    fn names(&self, getter: NameGetter, count: c_int) -> Result<Vec<Option<String>>> {
+        if count == 0 {
+            return Ok(vec![]);
+        }
+
+        let mut name_ptrs = vec![std::ptr::null_mut(); count as usize];
+        let mut surplus = 0;
+        let status = unsafe {
+            getter(
+                self.env().inner,
+                self.inner,
+                name_ptrs.as_mut_ptr(),
+                std::ptr::null_mut(),
+                0,
+                &mut surplus,
+                0,
+                count - 1,
+            )
+        };
+        if status == CPXERR_NO_NAMES as c_int || (status == 0 && surplus == 0) {
+            return Ok(vec![None; count as usize]);
+        } else if status != 0 && status != CPXERR_NEGATIVE_SURPLUS as c_int {
+            return Err(errors::Cplex::from_code(self.env().inner, self.inner, status).into());
+        }
+
+        let mut store = vec![0 as c_char; (-surplus) as usize];
+        macros::cpx_lp_result!(unsafe {
+            getter(
+                self.env().inner,
+                self.inner,
+                name_ptrs.as_mut_ptr(),
+                store.as_mut_ptr(),
+                store.len() as c_int,
+                &mut surplus,
+                0,
+                count - 1,
+            )
+        })?;
+
+        Ok(name_ptrs
+            .into_iter()
+            .map(|p| {
+                (!p.is_null()).then(|| unsafe { CStr::from_ptr(p) }.to_string_lossy().into_owned())
+            })
+            .collect())
+    }
+
+    fn doubles(&self, getter: DoubleGetter, count: usize) -> Result<Vec<f64>> {
+        if count == 0 {
+            return Ok(vec![]);
+        }
+
+        let mut values = vec![0.0; count];
+        macros::cpx_lp_result!(unsafe {
+            getter(
+                self.env().inner,
+                self.inner,
+                values.as_mut_ptr(),
+                0,
+                count as c_int - 1,
+            )
+        })?;
+        Ok(values)
+    }
+
+    fn sparse_vectors(&self, getter: SparseGetter, count: c_int) -> Result<Vec<Vec<(usize, f64)>>> {
+        if count == 0 {
+            return Ok(vec![]);
+        }
+
+        let mut nzcnt = 0;
+        let mut surplus = 0;
+        let mut beg = vec![0; count as usize];
+        let status = unsafe {
+            getter(
+                self.env().inner,
+                self.inner,
+                &mut nzcnt,
+                beg.as_mut_ptr(),
+                std::ptr::null_mut(),
+                std::ptr::null_mut(),
+                0,
+                &mut surplus,
+                0,
+                count - 1,
+            )
+        };
+        if status != 0 && status != CPXERR_NEGATIVE_SURPLUS as c_int {
+            return Err(errors::Cplex::from_code(self.env().inner, self.inner, status).into());
+        }
+
+        let space = -surplus;
+        let mut ind = vec![0; space as usize];
+        let mut val = vec![0.0; space as usize];
+        macros::cpx_lp_result!(unsafe {
+            getter(
+                self.env().inner,
+                self.inner,
+                &mut nzcnt,
+                beg.as_mut_ptr(),
+                ind.as_mut_ptr(),
+                val.as_mut_ptr(),
+                space,
+                &mut surplus,
+                0,
+                count - 1,
+            )
+        })?;
+
+        let ends = beg.iter().skip(1).copied().chain(std::iter::once(nzcnt));
+        Ok(beg
+            .iter()
+            .zip(ends)
+            .map(|(&beg, end)| {
+                (beg as usize..end as usize)
+                    .map(|k| (ind[k] as usize, val[k]))
+                    .collect()
+            })
+            .collect())
+    }
+
+    /// Get the simplex basis of the problem.
+    ///
+    /// The problem must have been solved through `solve_as` with `ProblemType::Linear`.
+    pub fn basis(&self) -> Result<Basis> {
+        let mut cstat = vec![0; self.variables.len()];
+        let mut rstat = vec![0; self.constraints.len()];
+        macros::cpx_lp_result!(unsafe {
+            CPXgetbase(
+                self.env().inner,
+                self.inner,
+                cstat.as_mut_ptr(),
+                rstat.as_mut_ptr(),
+            )
+        })?;
+
+        let to_statuses = |raw: Vec<c_int>| {
+            raw.into_iter()
+                .map(|s| {
+                    BasisStatus::from_raw(s).ok_or_else(|| {
+                        errors::Input::from_message(format!("Unknown basis status {s}")).into()
+                    })
+                })
+                .collect::<Result<Vec<_>>>()
+        };
+        Ok(Basis::new(self.token, to_statuses(cstat)?, to_statuses(rstat)?).with_model(self.model))
+    }
+
+    /// Load a simplex basis into the problem, to warm start the next LP solve.
+    ///
+    /// The basis must come from this problem, and have a status for every variable and
+    /// constraint of it.
+    pub fn set_basis(&mut self, basis: &Basis) -> Result<()> {
+        if basis.problem() != self.token {
+            return Err(errors::Input::from_message(format!(
+                "The basis does not come from problem {}",
+                self.name
+            ))
+            .into());
+        }
+        if basis.variable_statuses().len() != self.variables.len()
+            || basis.constraint_statuses().len() != self.constraints.len()
+        {
+            return Err(errors::Input::from_message(format!(
+                "Basis has {} variables and {} constraints, while the problem has {} variables and {} constraints",
+                basis.variable_statuses().len(),
+                basis.constraint_statuses().len(),
+                self.variables.len(),
+                self.constraints.len(),
+            ))
+            .into());
+        }
+
+        let cstat = basis
+            .variable_statuses()
+            .iter()
+            .map(|s| s.into_raw())
+            .collect::<Vec<_>>();
+        let rstat = basis
+            .constraint_statuses()
+            .iter()
+            .map(|s| s.into_raw())
+            .collect::<Vec<_>>();
+        macros::cpx_lp_result!(unsafe {
+            CPXcopybase(self.env().inner, self.inner, cstat.as_ptr(), rstat.as_ptr())
+        })
+    }
+
+    /// Write the simplex basis of the problem to a file named `name`, in the MPS basis format.
+    pub fn write_basis<S>(&self, name: S) -> Result<()>
+    where
+        S: AsRef<str>,
+    {
+        let name =
+            CString::new(name.as_ref()).map_err(|e| errors::Input::from_message(e.to_string()))?;
+
+        macros::cpx_lp_result!(unsafe {
+            CPXmbasewrite(self.env().inner, self.inner, name.as_ptr())
+        })
+    }
+
+    /// Read a simplex basis from a file named `name`, in the MPS basis format, and load it
+    /// into the problem.
+    pub fn read_basis<S>(&mut self, name: S) -> Result<()>
+    where
+        S: AsRef<str>,
+    {
+        let name =
+            CString::new(name.as_ref()).map_err(|e| errors::Input::from_message(e.to_string()))?;
+
+        macros::cpx_lp_result!(unsafe {
+            CPXreadcopybase(self.env().inner, self.inner, name.as_ptr())
+        })
+    }
+
+    /// Get the ranges over which the objective coefficient of every variable can vary
+    /// without changing the optimal basis.
+    ///
+    /// The problem must have been solved through `solve_as` with `ProblemType::Linear`.
+    pub fn objective_ranges(&self) -> Result<Vec<(VariableId, SensitivityRange)>> {
+        self.ensure_optimal_basis("objective_ranges")?;
+        if self.variables.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let mut lower = vec![0.0; self.variables.len()];
+        let mut upper = vec![0.0; self.variables.len()];
+        macros::cpx_lp_result!(unsafe {
+            CPXobjsa(
+                self.env().inner,
+                self.inner,
+                0,
+                self.variables.len() as c_int - 1,
+                lower.as_mut_ptr(),
+                upper.as_mut_ptr(),
+            )
+        })?;
+
+        Ok(lower
+            .into_iter()
+            .zip(upper)
+            .enumerate()
+            .map(|(idx, (lower, upper))| (self.variable_id(idx), SensitivityRange { lower, upper }))
+            .collect())
+    }
+
+    /// Get the ranges over which the lower and upper bound of every variable can vary
+    /// without changing the optimal basis.
+    ///
+    /// The problem must have been solved through `solve_as` with `ProblemType::Linear`.
+    pub fn bound_ranges(&self) -> Result<Vec<(VariableId, BoundRanges)>> {
+        self.ensure_optimal_basis("bound_ranges")?;
+        if self.variables.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let mut lblower = vec![0.0; self.variables.len()];
+        let mut lbupper = vec![0.0; self.variables.len()];
+        let mut ublower = vec![0.0; self.variables.len()];
+        let mut ubupper = vec![0.0; self.variables.len()];
+        macros::cpx_lp_result!(unsafe {
+            CPXboundsa(
+                self.env().inner,
+                self.inner,
+                0,
+                self.variables.len() as c_int - 1,
+                lblower.as_mut_ptr(),
+                lbupper.as_mut_ptr(),
+                ublower.as_mut_ptr(),
+                ubupper.as_mut_ptr(),
+            )
+        })?;
+
+        Ok((0..self.variables.len())
+            .map(|idx| {
+                (
+                    self.variable_id(idx),
+                    BoundRanges {
+                        lower_bound: SensitivityRange {
+                            lower: lblower[idx],
+                            upper: lbupper[idx],
+                        },
+                        upper_bound: SensitivityRange {
+                            lower: ublower[idx],
+                            upper: ubupper[idx],
+                        },
+                    },
+                )
+            })
+            .collect())
+    }
+
+    /// Get the ranges over which the right hand side of every constraint can vary
+    /// without changing the optimal basis.
+    ///
+    /// The problem must have been solved through `solve_as` with `ProblemType::Linear`.
+    pub fn rhs_ranges(&self) -> Result<Vec<(ConstraintId, SensitivityRange)>> {
+        self.ensure_optimal_basis("rhs_ranges")?;
+        if self.constraints.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let mut lower = vec![0.0; self.constraints.len()];
+        let mut upper = vec![0.0; self.constraints.len()];
+        macros::cpx_lp_result!(unsafe {
+            CPXrhssa(
+                self.env().inner,
+                self.inner,
+                0,
+                self.constraints.len() as c_int - 1,
+                lower.as_mut_ptr(),
+                upper.as_mut_ptr(),
+            )
+        })?;
+
+        Ok(lower
+            .into_iter()
+            .zip(upper)
+            .enumerate()
+            .map(|(idx, (lower, upper))| {
+                (self.constraint_id(idx), SensitivityRange { lower, upper })
+            })
+            .collect())
+    }
+
+    /// Get a direction along which the objective of an unbounded linear problem improves
+    /// indefinitely, as the nonzero entries of the ray.
+    ///
+    /// The problem must have been found unbounded by the simplex optimizer through `solve_as`
+    /// with `ProblemType::Linear`, with presolve disabled through
+    /// `parameters::preprocessing::Presolve`.
+    pub fn unbounded_ray(&self) -> Result<Vec<(VariableId, f64)>> {
+        self.ensure_linear("unbounded_ray")?;
+
+        let mut ray = vec![0.0; self.variables.len()];
+        macros::cpx_lp_result!(unsafe {
+            CPXgetray(self.env().inner, self.inner, ray.as_mut_ptr())
+        })?;
+
+        Ok(ray
+            .into_iter()
+            .enumerate()
+            .filter(|&(_, value)| value != 0.0)
+            .map(|(idx, value)| (self.variable_id(idx), value))
+            .collect())
+    }
+
+    /// Get a dual Farkas certificate proving the infeasibility of a linear problem.
+    ///
+    /// The problem must have been found infeasible by the dual simplex optimizer through
+    /// `solve_as` with `ProblemType::Linear`, see `parameters::lp_method::LPMethod`. Since
+    /// presolve may detect infeasibility before the optimizer runs, it is usually disabled
+    /// through `parameters::preprocessing::Presolve`.
+    pub fn farkas_certificate(&self) -> Result<FarkasCertificate> {
+        self.ensure_linear("farkas_certificate")?;
+
+        let mut y = vec![0.0; self.constraints.len()];
+        let mut proof = 0.0;
+        macros::cpx_lp_result!(unsafe {
+            CPXdualfarkas(self.env().inner, self.inner, y.as_mut_ptr(), &mut proof)
+        })?;
+
+        let multipliers = y
+            .into_iter()
+            .enumerate()
+            .filter(|&(_, value)| value != 0.0)
+            .map(|(idx, value)| (self.constraint_id(idx), value))
+            .collect();
+        Ok(FarkasCertificate::new(multipliers, proof))
+    }
+
+    /// Get the numerical quality measures of the current solution of the problem.
+    ///
+    /// The problem must have been solved through `solve_as`.
+    pub fn solution_quality(&self) -> Result<SolutionQuality> {
+        let mut max_primal_infeasibility = 0.0;
+        macros::cpx_lp_result!(unsafe {
+            CPXgetdblquality(
+                self.env().inner,
+                self.inner,
+                &mut max_primal_infeasibility,
+                CPX_MAX_PRIMAL_INFEAS as c_int,
+            )
+        })?;
+
+        let mip_kappa = [
+            CPX_KAPPA_STABLE,
+            CPX_KAPPA_SUSPICIOUS,
+            CPX_KAPPA_UNSTABLE,
+            CPX_KAPPA_ILLPOSED,
+            CPX_KAPPA_MAX,
+            CPX_KAPPA_ATTENTION,
+        ]
+        .into_iter()
+        .map(|what| self.quality(what))
+        .collect::<Result<Vec<_>>>()?
+        .into_iter()
+        .collect::<Option<Vec<_>>>()
+        .map(|stats| MipKappaStats {
+            stable: stats[0],
+            suspicious: stats[1],
+            unstable: stats[2],
+            ill_posed: stats[3],
+            max_kappa: stats[4],
+            attention: stats[5],
+        });
+
+        Ok(SolutionQuality {
+            max_primal_infeasibility,
+            max_scaled_primal_infeasibility: self.quality(CPX_MAX_SCALED_PRIMAL_INFEAS)?,
+            max_dual_infeasibility: self.quality(CPX_MAX_DUAL_INFEAS)?,
+            max_scaled_dual_infeasibility: self.quality(CPX_MAX_SCALED_DUAL_INFEAS)?,
+            max_integer_infeasibility: self.quality(CPX_MAX_INT_INFEAS)?,
+            kappa: self.quality(CPX_KAPPA)?,
+            mip_kappa,
+        })
+    }
+
+    /// Get a quality measure of the current solution, or `None` if CPLEX reports that it is
+    /// not available for this kind of solution.
+    fn quality(&self, what: u32) -> Result<Option<f64>> {
+        const NOT_AVAILABLE: [u32; 6] = [
+            CPXERR_NO_KAPPASTATS,
+            CPXERR_NOT_FOR_MIP,
+            CPXERR_NOT_MIP,
+            CPXERR_NO_DUAL_SOLN,
+            CPXERR_NO_BASIS,
+            CPXERR_NO_BASIC_SOLN,
+        ];
+
+        let mut value = 0.0;
+        let status =
+            unsafe { CPXgetdblquality(self.env().inner, self.inner, &mut value, what as c_int) };
+        if NOT_AVAILABLE.iter().any(|&code| status == code as c_int) {
+            Ok(None)
+        } else if status != 0 {
+            Err(errors::Cplex::from_code(self.env().inner, self.inner, status).into())
+        } else {
+            Ok(Some(value))
+        }
+    }
+
+    fn ensure_linear(&self, caller: &str) -> Result<()> {
+        let ty = unsafe { CPXgetprobtype(self.env().inner, self.inner) };
+        if ty != CPXPROB_LP as c_int {
+            return Err(errors::Input::from_message(format!(
+                "Called {caller} on a problem which has not been solved as ProblemType::Linear"
+            ))
+            .into());
+        }
+        Ok(())
+    }
+
+    /// Check that the problem holds an optimal basic solution of an LP solve, which is
+    /// required by the sensitivity analysis.
+    fn ensure_optimal_basis(&self, caller: &str) -> Result<()> {
+        self.ensure_linear(caller)?;
+
+        let mut method = 0;
+        let mut solution_type = 0;
+        let mut primal_feasible = 0;
+        let mut dual_feasible = 0;
+        macros::cpx_lp_result!(unsafe {
+            CPXsolninfo(
+                self.env().inner,
+                self.inner,
+                &mut method,
+                &mut solution_type,
+                &mut primal_feasible,
+                &mut dual_feasible,
+            )
+        })?;
+        if solution_type != CPX_BASIC_SOLN as c_int || primal_feasible == 0 || dual_feasible == 0 {
+            return Err(errors::Input::from_message(format!(
+                "Called {caller} on a problem without an optimal basic solution"
+            ))
+            .into());
+        }
+        Ok(())
+    }
+
+    /// Add an initial solution to the problem.
+    ///
+    /// `vars` is an array of indices (i.e. the result of `prob.add_variable`) and `values` are
+    /// their values.
+    pub fn add_initial_soln(&mut self, vars: &[VariableId], values: &[f64]) -> Result<()> {
+        if values.len() != vars.len() {
+            return Err(errors::Input::from_message(
+                "number of solution variables and values does not match".to_string(),
+            )
+            .into());
+        }
+        let pairs = vars.iter().copied().zip(values.iter().copied());
+        self.check_values("MIP start value", &pairs.collect::<Vec<_>>())?;
+        let vars = vars.iter().map(|&u| u.index as c_int).collect::<Vec<_>>();
+
+        macros::cpx_lp_result!(unsafe {
+            CPXaddmipstarts(
+                self.env().inner,
+                self.inner,
+                1,
+                vars.len() as c_int,
+                &0,
+                vars.as_ptr(),
+                values.as_ptr(),
+                &0,
+                &mut std::ptr::null_mut(),
+            )
+        })
+    }
+
+    /// Add a new long annotation to the problem, with the given default value.
+    ///
+    /// The id for the annotation is returned.
+    pub fn add_long_annotation<S>(&mut self, name: S, default_value: i64) -> Result<AnnotationId>
+    where
+        S: AsRef<str>,
+    {
+        let name =
+            CString::new(name.as_ref()).map_err(|e| errors::Input::from_message(e.to_string()))?;
+
+        macros::cpx_lp_result!(unsafe {
+            CPXnewlongannotation(self.env().inner, self.inner, name.as_ptr(), default_value)
+        })?;
+
+        let count = unsafe { CPXgetnumlongannotations(self.env().inner, self.inner) };
+        Ok(AnnotationId(count as usize - 1))
+    }
+
+    /// Set the value of a long annotation for the given variables.
+    pub fn set_variable_annotations(
+        &mut self,
+        annotation: AnnotationId,
+        values: &[(VariableId, i64)],
+    ) -> Result<()> {
+        self.check_variables(values.iter().map(|(v, _)| v))?;
+        let (ind, val): (Vec<c_int>, Vec<i64>) = values
+            .iter()
+            .map(|&(var_id, value)| (var_id.index as c_int, value))
+            .unzip();
+
+        macros::cpx_lp_result!(unsafe {
+            CPXsetlongannotations(
+                self.env().inner,
+                self.inner,
+                annotation.0 as c_int,
+                CPX_ANNOTATIONOBJ_COL as c_int,
+                ind.len() as c_int,
+                ind.as_ptr(),
+                val.as_ptr(),
+            )
+        })
+    }
+
+    /// Assign variables to the master problem or to a subproblem of a Benders decomposition.
+    ///
+    /// Variables which are not explicitly assigned belong to the master problem. The
+    /// partitions are only taken into account when the `parameters::benders::Strategy`
+    /// parameter is set to `User`, `Workers` or `Full`.
+    pub fn set_benders_partitions(
+        &mut self,
+        partitions: &[(VariableId, BendersPartition)],
+    ) -> Result<()> {
+        let annotation = self.benders_annotation()?;
+        let values = partitions
+            .iter()
+            .map(|&(var_id, partition)| (var_id, partition.into_raw()))
+            .collect::<Vec<_>>();
+        self.set_variable_annotations(annotation, &values)
+    }
+
+    fn benders_annotation(&mut self) -> Result<AnnotationId> {
+        let name = CStr::from_bytes_with_nul(CPX_BENDERS_ANNOTATION)
+            .expect("Invalid benders annotation name");
+        let mut index: c_int = -1;
+        let status = unsafe {
+            CPXgetlongannotationindex(self.env().inner, self.inner, name.as_ptr(), &mut index)
+        };
+        if status == 0 && index >= 0 {
+            return Ok(AnnotationId(index as usize));
+        }
+
+        self.add_long_annotation(
+            name.to_str().expect("Invalid benders annotation name"),
+            CPX_BENDERS_MASTERVALUE as i64,
+        )
+    }
+
+    /// Solve the Problem, returning a `Solution` object with the
+    /// result.
+    ///
+    /// The problem can still be queried afterwards, e.g. to write its solution to a file, or
+    /// modified and solved again.
+    pub fn solve_as(&self, pt: ProblemType) -> Result<Solution> {
+        #[cfg(feature = "tracing")]
+        let span = tracing::info_span!(
+            "cplex_solve",
+            problem = %self.name,
+            problem_type = ?pt,
+            variables = self.variables.len(),
+            constraints = self.constraints.len(),
+            status = tracing::field::Empty,
+            objective_value = tracing::field::Empty,
+            error = tracing::field::Empty,
+        )
+        .entered();
+
+        let capture = self
+            .capture_log
+            .then(|| self.env().capture_messages())
+            .transpose()?;
+
+        let result = self.solve(pt);
+
+        let result = match (result, capture.map(|c| c.finish())) {
+            (Ok(solution), Some(log)) => Ok(solution.with_solve_log(log)),
+            (Err(error), Some(log)) => Err(errors::Error::Logged {
+                error: Box::new(error),
+                log,
+            }),
+            (result, None) => result,
+        };
+
+        #[cfg(feature = "tracing")]
+        {
+            span.record("status", unsafe {
+                CPXgetstat(self.env().inner, self.inner)
+            });
+            match &result {
+                Ok(solution) => span.record("objective_value", solution.objective_value()),
+                Err(e) => span.record("error", tracing::field::display(e)),
+            };
+        }
+
+        result
+    }
+
+    fn solve(&self, pt: ProblemType) -> Result<Solution> {
+        macros::cpx_lp_result!(unsafe {
+            CPXchgprobtype(self.env().inner, self.inner, pt.into_raw())
+        })?;
+
+        if let Some(handler) = &self.progress_handler {
+            handler.reset_start();
+        }
+
+        let start_ticks = self.env().deterministic_time()?;
+
+        let start_optim = Instant::now();
         match pt {
+            _ if !self.objectives.is_empty() => macros::cpx_lp_result!(unsafe {
+                CPXmultiobjopt(self.env().inner, self.inner, std::ptr::null())
+            })?,
             ProblemType::MixedInteger => {
-                macros::cpx_lp_result!(unsafe { CPXmipopt(self.env.inner, self.inner) })?
+                macros::cpx_lp_result!(unsafe { CPXmipopt(self.env().inner, self.inner) })?
             }
             ProblemType::Linear => {
-                macros::cpx_lp_result!(unsafe { CPXlpopt(self.env.inner, self.inner) })?
+                macros::cpx_lp_result!(unsafe { CPXlpopt(self.env().inner, self.inner) })?
             }
         };
         let elapsed = start_optim.elapsed();
         debug!("CPLEX model solution took: {:?}", elapsed);
 
-        let code = unsafe { CPXgetstat(self.env.inner, self.inner) };
-        if code as u32 == CPX_STAT_INFEASIBLE || code as u32 == CPX_STAT_INForUNBD {
+        let end_ticks = self.env().deterministic_time()?;
+
+        let code = unsafe { CPXgetstat(self.env().inner, self.inner) };
+        if code as u32 == CPX_STAT_INFEASIBLE
+            || code as u32 == CPX_STAT_INForUNBD
+            || code as u32 == CPX_STAT_MULTIOBJ_INFEASIBLE
+            || code as u32 == CPX_STAT_MULTIOBJ_INForUNBD
+        {
             return Err(crate::errors::Cplex::Unfeasible {
                 code,
                 message: "Unfeasible problem".to_string(),
@@ -443,7 +2156,10 @@ impl Problem {
             .into());
         }
 
-        if code as u32 == CPX_STAT_UNBOUNDED || code as u32 == CPXMIP_UNBOUNDED {
+        if code as u32 == CPX_STAT_UNBOUNDED
+            || code as u32 == CPXMIP_UNBOUNDED
+            || code as u32 == CPX_STAT_MULTIOBJ_UNBOUNDED
+        {
             return Err(crate::errors::Cplex::Unbounded {
                 code,
                 message: "Unbounded problem".to_string(),
@@ -453,13 +2169,13 @@ impl Problem {
 
         let mut objective_value: f64 = 0.0;
         macros::cpx_lp_result!(unsafe {
-            CPXgetobjval(self.env.inner, self.inner, &mut objective_value)
+            CPXgetobjval(self.env().inner, self.inner, &mut objective_value)
         })?;
 
         let mut variable_values = vec![0f64; self.variables.len()];
         macros::cpx_lp_result!(unsafe {
             CPXgetx(
-                self.env.inner,
+                self.env().inner,
                 self.inner,
                 variable_values.as_mut_ptr(),
                 0,
@@ -467,227 +2183,1499 @@ impl Problem {
             )
         })?;
 
-        Ok(Solution::new(variable_values, objective_value))
-    }
-}
+        let objective_values = (0..self.objectives.len())
+            .map(|n| {
+                let mut value = 0.0;
+                macros::cpx_lp_result!(unsafe {
+                    CPXmultiobjgetobjval(self.env().inner, self.inner, n as c_int, &mut value)
+                })
+                .map(|_| value)
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let stats = self.solve_stats(pt, elapsed, end_ticks - start_ticks)?;
+
+        let variable_names = self
+            .variables
+            .iter()
+            .enumerate()
+            .map(|(index, v)| names::variable_name(v.name(), index).into_owned())
+            .collect();
+        let constraint_names = self
+            .constraints
+            .iter()
+            .enumerate()
+            .map(|(index, c)| names::constraint_name(c.name(), index).into_owned())
+            .collect();
+
+        Ok(Solution::new(self.token, variable_values, objective_value)
+            .with_model(self.model)
+            .with_names(variable_names, constraint_names)
+            .with_objective_values(objective_values)
+            .with_stats(stats))
+    }
+
+    fn solve_stats(
+        &self,
+        pt: ProblemType,
+        wall_time: Duration,
+        deterministic_time: f64,
+    ) -> Result<SolveStats> {
+        let barrier_iterations = unsafe { CPXgetbaritcnt(self.env().inner, self.inner) } as u64;
+
+        match pt {
+            ProblemType::MixedInteger => {
+                let mut best_bound = 0.0;
+                macros::cpx_lp_result!(unsafe {
+                    CPXgetbestobjval(self.env().inner, self.inner, &mut best_bound)
+                })?;
+                let mut gap = 0.0;
+                macros::cpx_lp_result!(unsafe {
+                    CPXgetmiprelgap(self.env().inner, self.inner, &mut gap)
+                })?;
+
+                Ok(SolveStats {
+                    best_bound: Some(best_bound),
+                    mip_relative_gap: Some(gap),
+                    nodes: Some(unsafe { CPXgetnodecnt(self.env().inner, self.inner) } as u64),
+                    simplex_iterations: unsafe { CPXgetmipitcnt(self.env().inner, self.inner) }
+                        as u64,
+                    barrier_iterations,
+                    wall_time,
+                    deterministic_time,
+                })
+            }
+            ProblemType::Linear => Ok(SolveStats {
+                best_bound: None,
+                mip_relative_gap: None,
+                nodes: None,
+                simplex_iterations: unsafe { CPXgetitcnt(self.env().inner, self.inner) } as u64,
+                barrier_iterations,
+                wall_time,
+                deterministic_time,
+            }),
+        }
+    }
+}
+
+#[cfg(feature = "cplex")]
+impl<E: BorrowMut<Environment>> Problem<E> {
+    /// Get a mutable reference to the environment of the problem.
+    ///
+    /// This is only available when the problem has exclusive access to its environment.
+    pub fn env_mut(&mut self) -> &mut Environment {
+        self.env.borrow_mut()
+    }
+
+    /// Route all the CPLEX output channels of the environment to the `log` crate, using
+    /// `cplex::<problem name>` as target.
+    ///
+    /// See `Environment::route_to_log` for the mapping between streams and log levels.
+    pub fn route_to_log(&mut self) -> Result<()> {
+        let target = format!("cplex::{}", self.name);
+        self.env_mut().route_to_log_with_target(target)
+    }
+}
+
+#[cfg(feature = "cplex")]
+impl<E: Borrow<Environment>> Drop for Problem<E> {
+    fn drop(&mut self) {
+        unsafe {
+            assert_eq!(CPXfreeprob(self.env().inner, &mut self.inner), 0);
+        }
+    }
+}
+
+#[cfg(all(test, feature = "cplex"))]
+mod test {
+    use constants::INFINITY;
+    use constraints::ConstraintType;
+
+    use super::*;
+    use logging::StreamType;
+    use variables::{Variable, VariableType};
+
+    /// A path in the temporary directory that no other test, or test run, uses
+    fn temp_path(file_name: &str) -> std::path::PathBuf {
+        static COUNTER: std::sync::atomic::AtomicUsize = std::sync::atomic::AtomicUsize::new(0);
+        let count = COUNTER.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+        std::env::temp_dir().join(format!(
+            "cplex_rs_{}_{count}_{file_name}",
+            std::process::id()
+        ))
+    }
+
+    #[test]
+    fn mipex1() {
+        let env = Environment::new().unwrap();
+        let mut problem = Problem::new(env, "mipex1").unwrap();
+
+        let x0 = problem
+            .add_variable(Variable::new(
+                VariableType::Continuous,
+                1.0,
+                0.0,
+                40.0,
+                "x0",
+            ))
+            .unwrap();
+
+        let x1 = problem
+            .add_variable(Variable::new(
+                VariableType::Continuous,
+                2.0,
+                0.0,
+                INFINITY,
+                "x1",
+            ))
+            .unwrap();
+
+        let x2 = problem
+            .add_variable(Variable::new(
+                VariableType::Continuous,
+                3.0,
+                0.0,
+                INFINITY,
+                "x2",
+            ))
+            .unwrap();
+
+        let x3 = problem
+            .add_variable(Variable::new(VariableType::Integer, 1.0, 2.0, 3.0, "x3"))
+            .unwrap();
+
+        assert_eq!(x0.into_inner(), 0);
+        assert_eq!(x1.into_inner(), 1);
+        assert_eq!(x2.into_inner(), 2);
+        assert_eq!(x3.into_inner(), 3);
+
+        let c0 = problem
+            .add_constraint(Constraint::new(
+                ConstraintType::LessThanEq,
+                20.0,
+                None,
+                vec![(x0, -1.0), (x1, 1.0), (x2, 1.0), (x3, 10.0)],
+            ))
+            .unwrap();
+
+        let c1 = problem
+            .add_constraint(Constraint::new(
+                ConstraintType::LessThanEq,
+                30.0,
+                None,
+                vec![(x0, 1.0), (x1, -3.0), (x2, 1.0)],
+            ))
+            .unwrap();
+
+        let c2 = problem
+            .add_constraint(Constraint::new(
+                ConstraintType::Eq,
+                0.0,
+                None,
+                vec![(x1, 1.0), (x3, -3.5)],
+            ))
+            .unwrap();
+
+        assert_eq!(c0.into_inner(), 0);
+        assert_eq!(c1.into_inner(), 1);
+        assert_eq!(c2.into_inner(), 2);
+
+        let problem = problem.set_objective_type(ObjectiveType::Maximize).unwrap();
+
+        let solution = problem.solve_as(ProblemType::MixedInteger).unwrap();
+
+        assert_eq!(solution.objective_value(), 122.5);
+    }
+
+    #[test]
+    fn mip_solve_stats() {
+        let env = Environment::new().unwrap();
+        let mut problem = Problem::new(env, "mip_solve_stats").unwrap();
+
+        let vars = problem
+            .add_variables(vec![
+                Variable::new(VariableType::Integer, 1.0, 0.0, 10.0, "x0"),
+                Variable::new(VariableType::Integer, 2.0, 0.0, 10.0, "x1"),
+            ])
+            .unwrap();
+        problem
+            .add_constraint(Constraint::new(
+                ConstraintType::LessThanEq,
+                7.5,
+                None,
+                vec![(vars[0], 1.0), (vars[1], 2.0)],
+            ))
+            .unwrap();
+
+        let problem = problem.set_objective_type(ObjectiveType::Maximize).unwrap();
+        let solution = problem.solve_as(ProblemType::MixedInteger).unwrap();
+        assert_eq!(solution.objective_value(), 7.0);
+
+        let stats = solution.stats();
+        assert!(stats.best_bound.unwrap() >= solution.objective_value());
+        assert!(stats.mip_relative_gap.is_some());
+        assert!(stats.nodes.is_some());
+        assert!(stats.deterministic_time >= 0.0);
+    }
+
+    #[test]
+    fn mipex1_batch() {
+        let env = Environment::new().unwrap();
+        let mut problem = Problem::new(env, "mipex1").unwrap();
+
+        let vars = problem
+            .add_variables(vec![
+                Variable::new(VariableType::Continuous, 1.0, 0.0, 40.0, "x0"),
+                Variable::new(VariableType::Continuous, 2.0, 0.0, INFINITY, "x1"),
+                Variable::new(VariableType::Continuous, 3.0, 0.0, INFINITY, "x2"),
+                Variable::new(VariableType::Integer, 1.0, 2.0, 3.0, "x3"),
+            ])
+            .unwrap();
+
+        assert_eq!(
+            vars.iter().map(|id| id.into_inner()).collect::<Vec<_>>(),
+            vec![0, 1, 2, 3]
+        );
+
+        let cons = problem
+            .add_constraints(vec![
+                Constraint::new(
+                    ConstraintType::LessThanEq,
+                    20.0,
+                    None,
+                    vec![
+                        (vars[0], -1.0),
+                        (vars[1], 1.0),
+                        (vars[2], 1.0),
+                        (vars[3], 10.0),
+                    ],
+                ),
+                Constraint::new(
+                    ConstraintType::LessThanEq,
+                    30.0,
+                    None,
+                    vec![(vars[0], 1.0), (vars[1], -3.0), (vars[2], 1.0)],
+                ),
+                Constraint::new(
+                    ConstraintType::Eq,
+                    0.0,
+                    None,
+                    vec![(vars[1], 1.0), (vars[3], -3.5)],
+                ),
+            ])
+            .unwrap();
+
+        assert_eq!(
+            cons.iter().map(|id| id.into_inner()).collect::<Vec<_>>(),
+            vec![0, 1, 2]
+        );
+
+        let problem = problem.set_objective_type(ObjectiveType::Maximize).unwrap();
+
+        let solution = problem.solve_as(ProblemType::MixedInteger).unwrap();
+
+        assert_eq!(solution.objective_value(), 122.5);
+    }
+
+    #[test]
+    fn unfeasible() {
+        let env = Environment::new().unwrap();
+        let mut problem = Problem::new(env, "unfeasible").unwrap();
+
+        let vars = problem
+            .add_variables(vec![
+                Variable::new(VariableType::Continuous, 1.0, 0.0, 1.0, "x0"),
+                Variable::new(VariableType::Continuous, 1.0, 0.0, 1.0, "x1"),
+            ])
+            .unwrap();
+
+        assert_eq!(
+            vars.iter().map(|id| id.into_inner()).collect::<Vec<_>>(),
+            vec![0, 1]
+        );
+
+        let cons = problem
+            .add_constraints(vec![
+                Constraint::new(
+                    ConstraintType::Eq,
+                    0.0,
+                    None,
+                    vec![(vars[0], 1.0), (vars[1], 1.0)],
+                ),
+                Constraint::new(
+                    ConstraintType::Eq,
+                    1.0,
+                    None,
+                    vec![(vars[0], 1.0), (vars[1], 1.0)],
+                ),
+            ])
+            .unwrap();
+
+        assert_eq!(
+            cons.iter().map(|id| id.into_inner()).collect::<Vec<_>>(),
+            vec![0, 1]
+        );
 
-impl Drop for Problem {
-    fn drop(&mut self) {
-        unsafe {
-            assert_eq!(CPXfreeprob(self.env.inner, &mut self.inner), 0);
-        }
+        let problem = problem.set_objective_type(ObjectiveType::Maximize).unwrap();
+        assert!(matches!(
+            problem.solve_as(ProblemType::Linear),
+            Err(errors::Error::Cplex(errors::Cplex::Unfeasible { .. }))
+        ));
     }
-}
-
-#[cfg(test)]
-mod test {
-    use constants::INFINITY;
-    use constraints::ConstraintType;
-
-    use super::*;
-    use variables::{Variable, VariableType};
 
     #[test]
-    fn mipex1() {
+    fn unbounded() {
         let env = Environment::new().unwrap();
-        let mut problem = Problem::new(env, "mipex1").unwrap();
+        let mut problem = Problem::new(env, "unbounded").unwrap();
 
-        let x0 = problem
+        problem
             .add_variable(Variable::new(
-                VariableType::Continuous,
+                VariableType::Integer,
                 1.0,
                 0.0,
-                40.0,
+                INFINITY,
                 "x0",
             ))
             .unwrap();
 
-        let x1 = problem
+        let problem = problem.set_objective_type(ObjectiveType::Maximize).unwrap();
+
+        assert!(matches!(
+            problem.solve_as(ProblemType::MixedInteger),
+            Err(errors::Error::Cplex(errors::Cplex::Unbounded { .. }))
+        ));
+    }
+
+    #[test]
+    fn benders_user_partitions() {
+        let mut env = Environment::new().unwrap();
+        env.set_parameter(parameters::benders::Strategy::User)
+            .unwrap();
+        let mut problem = Problem::new(env, "benders").unwrap();
+
+        let y = problem
+            .add_variable(Variable::new(VariableType::Binary, 10.0, 0.0, 1.0, "y"))
+            .unwrap();
+        let x = problem
             .add_variable(Variable::new(
                 VariableType::Continuous,
-                2.0,
+                1.0,
                 0.0,
                 INFINITY,
-                "x1",
+                "x",
+            ))
+            .unwrap();
+
+        problem
+            .add_constraints(vec![
+                Constraint::new(
+                    ConstraintType::LessThanEq,
+                    0.0,
+                    None,
+                    vec![(x, 1.0), (y, -5.0)],
+                ),
+                Constraint::new(ConstraintType::GreaterThanEq, 3.0, None, vec![(x, 1.0)]),
+            ])
+            .unwrap();
+
+        problem
+            .set_benders_partitions(&[
+                (y, BendersPartition::Master),
+                (x, BendersPartition::Subproblem(0)),
+            ])
+            .unwrap();
+
+        let solution = problem.solve_as(ProblemType::MixedInteger).unwrap();
+
+        assert_eq!(solution.objective_value(), 13.0);
+        assert_eq!(solution.variable_value(y), 1.0);
+    }
+
+    #[test]
+    fn multi_objective_lexicographic() {
+        let env = Environment::new().unwrap();
+        let mut problem = Problem::new(env, "multiobj").unwrap();
+
+        let vars = problem
+            .add_variables(vec![
+                Variable::new(VariableType::Continuous, 0.0, 0.0, 10.0, "x"),
+                Variable::new(VariableType::Continuous, 0.0, 0.0, 8.0, "y"),
+            ])
+            .unwrap();
+
+        problem
+            .add_constraint(Constraint::new(
+                ConstraintType::LessThanEq,
+                10.0,
+                None,
+                vec![(vars[0], 1.0), (vars[1], 1.0)],
+            ))
+            .unwrap();
+
+        let total = problem
+            .add_objective(Objective::new(
+                2,
+                1.0,
+                Some("total".to_owned()),
+                vec![(vars[0], -1.0), (vars[1], -1.0)],
+            ))
+            .unwrap();
+        let x_only = problem
+            .add_objective(Objective::new(
+                1,
+                1.0,
+                Some("x_only".to_owned()),
+                vec![(vars[0], 1.0)],
+            ))
+            .unwrap();
+
+        let solution = problem.solve_as(ProblemType::Linear).unwrap();
+
+        assert_eq!(solution.multi_objective_value(total), -10.0);
+        assert_eq!(solution.multi_objective_value(x_only), 2.0);
+        assert_eq!(solution.variable_value(vars[1]), 8.0);
+    }
+
+    #[test]
+    fn shared_environment() {
+        let env = std::rc::Rc::new(Environment::new().unwrap());
+
+        let mut first = Problem::new(std::rc::Rc::clone(&env), "first").unwrap();
+        let mut second = Problem::new(&*env, "second").unwrap();
+
+        let x = first
+            .add_variable(Variable::new(VariableType::Continuous, 1.0, 0.0, 1.0, "x"))
+            .unwrap();
+        let y = second
+            .add_variable(Variable::new(VariableType::Continuous, 2.0, 0.0, 1.0, "y"))
+            .unwrap();
+
+        let first = first.set_objective_type(ObjectiveType::Maximize).unwrap();
+        let second = second.set_objective_type(ObjectiveType::Maximize).unwrap();
+
+        let first_solution = first.solve_as(ProblemType::Linear).unwrap();
+        let second_solution = second.solve_as(ProblemType::Linear).unwrap();
+
+        assert_eq!(first_solution.variable_value(x), 1.0);
+        assert_eq!(second_solution.variable_value(y), 1.0);
+        assert_eq!(second_solution.objective_value(), 2.0);
+    }
+
+    #[test]
+    fn solve_log_capture() {
+        let env = Environment::new().unwrap();
+        let mut problem = Problem::new(env, "solve_log").unwrap();
+        problem.set_log_capture(true);
+
+        problem
+            .add_variable(Variable::new(VariableType::Integer, 1.0, 0.0, 10.0, "x"))
+            .unwrap();
+
+        let problem = problem.set_objective_type(ObjectiveType::Maximize).unwrap();
+        let solution = problem.solve_as(ProblemType::MixedInteger).unwrap();
+
+        let log = solution.solve_log().unwrap();
+        assert!(log.iter().any(|m| m.stream == StreamType::Log));
+    }
+
+    /// A `log` logger keeping the target and level of every record, installed once for
+    /// the whole test binary.
+    struct RecordingLogger(std::sync::Mutex<Vec<(String, log::Level)>>);
+
+    impl log::Log for RecordingLogger {
+        fn enabled(&self, _: &log::Metadata) -> bool {
+            true
+        }
+
+        fn log(&self, record: &log::Record) {
+            self.0
+                .lock()
+                .unwrap()
+                .push((record.target().to_owned(), record.level()));
+        }
+
+        fn flush(&self) {}
+    }
+
+    static LOGGER: RecordingLogger = RecordingLogger(std::sync::Mutex::new(vec![]));
+
+    /// The levels of the records logged so far with the given target
+    fn logged_levels(target: &str) -> Vec<log::Level> {
+        static INIT: std::sync::Once = std::sync::Once::new();
+        INIT.call_once(|| {
+            log::set_logger(&LOGGER).unwrap();
+            log::set_max_level(log::LevelFilter::Trace);
+        });
+        LOGGER
+            .0
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|(t, _)| t == target)
+            .map(|&(_, level)| level)
+            .collect()
+    }
+
+    #[test]
+    fn route_to_log() {
+        assert!(logged_levels("cplex").is_empty());
+        let problem = |env: Environment, name: &str| {
+            let mut problem = Problem::new(env, name).unwrap();
+            problem
+                .add_variable(Variable::new(VariableType::Integer, 1.0, 0.0, 10.0, "x"))
+                .unwrap();
+            problem.set_objective_type(ObjectiveType::Maximize).unwrap()
+        };
+
+        let mut env = Environment::new().unwrap();
+        env.route_to_log().unwrap();
+        let routed = problem(env, "route_env");
+        routed.solve_as(ProblemType::MixedInteger).unwrap();
+        let levels = logged_levels("cplex");
+        assert!(!levels.is_empty());
+        // A feasible MIP only emits messages on the results and log streams
+        assert!(levels.iter().all(|&level| level == log::Level::Info));
+
+        let mut routed = problem(Environment::new().unwrap(), "route_problem");
+        routed.route_to_log().unwrap();
+        routed.solve_as(ProblemType::MixedInteger).unwrap();
+        let levels = logged_levels("cplex::route_problem");
+        assert!(levels.contains(&log::Level::Info));
+        assert!(!levels.contains(&log::Level::Error));
+
+        // Failures are reported on the error stream
+        let missing = temp_path("missing").join("route.lp");
+        assert!(routed.write(missing.to_str().unwrap()).is_err());
+        let levels = logged_levels("cplex::route_problem");
+        assert!(levels.contains(&log::Level::Error));
+    }
+
+    #[test]
+    fn mip_progress_channel() {
+        // Without presolve, cuts and heuristics, the knapsack below can only be solved by
+        // branching: all the weights are even while the capacity is odd.
+        let mut env = Environment::new().unwrap();
+        env.set_parameter(parameters::preprocessing::Presolve(false))
+            .unwrap();
+        env.set_parameter(parameters::mip::limits::CutPasses::None)
+            .unwrap();
+        env.set_parameter(parameters::mip::strategy::HeuristicFreq::None)
+            .unwrap();
+        env.set_parameter(parameters::Threads(1)).unwrap();
+        let mut problem = Problem::new(env, "progress").unwrap();
+
+        let vars = problem
+            .add_variables(
+                (0..20)
+                    .map(|i| {
+                        let value = 10.0 + i as f64;
+                        Variable::new(VariableType::Binary, value, 0.0, 1.0, format!("x{i}"))
+                    })
+                    .collect(),
+            )
+            .unwrap();
+        problem
+            .add_constraint(Constraint::new(
+                ConstraintType::LessThanEq,
+                101.0,
+                None,
+                vars.iter()
+                    .enumerate()
+                    .map(|(i, &v)| (v, 2.0 * (5 + i) as f64))
+                    .collect(),
+            ))
+            .unwrap();
+
+        let progress = problem.progress_channel().unwrap();
+        let problem = problem.set_objective_type(ObjectiveType::Maximize).unwrap();
+        let solution = problem.solve_as(ProblemType::MixedInteger).unwrap();
+
+        let updates = progress.try_iter().collect::<Vec<_>>();
+        assert!(!updates.is_empty());
+        assert!(updates.windows(2).all(|w| w[0].nodes <= w[1].nodes));
+        let last = updates.last().unwrap();
+        assert!(last.nodes > 0);
+        assert!(last.best_bound >= solution.objective_value() - 1e-6);
+    }
+
+    #[test]
+    fn mip_starts() {
+        let env = Environment::new().unwrap();
+        let mut problem = Problem::new(env, "mip_starts").unwrap();
+
+        let vars = problem
+            .add_variables(vec![
+                Variable::new(VariableType::Integer, 1.0, 0.0, 10.0, "x0"),
+                Variable::new(VariableType::Integer, 2.0, 0.0, 10.0, "x1"),
+            ])
+            .unwrap();
+
+        let ids = problem
+            .add_mip_starts(vec![
+                MipStart::new(
+                    MipStartEffort::CheckFeasibility,
+                    Some("first".to_owned()),
+                    vec![(vars[0], 1.0), (vars[1], 2.0)],
+                ),
+                MipStart::new(MipStartEffort::Repair, None, vec![(vars[1], 3.0)]),
+            ])
+            .unwrap();
+        assert_eq!(ids, vec![MipStartId(0), MipStartId(1)]);
+
+        let starts = problem.mip_starts().unwrap();
+        assert_eq!(starts.len(), 2);
+        assert_eq!(starts[0].name(), Some("first"));
+        assert_eq!(starts[0].effort(), MipStartEffort::CheckFeasibility);
+        assert_eq!(starts[0].values(), &[(vars[0], 1.0), (vars[1], 2.0)]);
+        assert_eq!(starts[1].effort(), MipStartEffort::Repair);
+        assert_eq!(starts[1].values(), &[(vars[1], 3.0)]);
+
+        problem.delete_mip_start(ids[0]).unwrap();
+        assert_eq!(problem.mip_starts().unwrap().len(), 1);
+        problem.delete_all_mip_starts().unwrap();
+        assert!(problem.mip_starts().unwrap().is_empty());
+    }
+
+    #[test]
+    fn mip_start_and_solution_files() {
+        let env = Environment::new().unwrap();
+        let mut problem = Problem::new(env, "solution_files").unwrap();
+
+        let vars = problem
+            .add_variables(vec![
+                Variable::new(VariableType::Integer, 1.0, 0.0, 10.0, "x0"),
+                Variable::new(VariableType::Integer, 2.0, 0.0, 10.0, "x1"),
+            ])
+            .unwrap();
+        problem
+            .add_constraint(Constraint::new(
+                ConstraintType::GreaterThanEq,
+                3.0,
+                Some("c0".to_owned()),
+                vec![(vars[0], 1.0), (vars[1], 1.0)],
             ))
             .unwrap();
+        let solution = problem.solve_as(ProblemType::MixedInteger).unwrap();
+
+        let sol_path = temp_path("solution_files.sol");
+        let mst_path = temp_path("solution_files.mst");
+
+        problem.write_solution(sol_path.to_str().unwrap()).unwrap();
+        let records = sol_file::read(&sol_path).unwrap();
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].objective_value, Some(solution.objective_value()));
+        assert_eq!(records[0].variables.len(), 2);
+        assert_eq!(records[0].variables[0].name, "x0");
+        assert_eq!(records[0].variables[0].value, 3.0);
+        assert_eq!(records[0].constraints[0].name, "c0");
+
+        problem
+            .add_mip_starts(vec![MipStart::new(
+                MipStartEffort::Repair,
+                Some("start".to_owned()),
+                vec![(vars[0], 1.0), (vars[1], 2.0)],
+            )])
+            .unwrap();
+        problem
+            .write_mip_starts(mst_path.to_str().unwrap())
+            .unwrap();
+        problem.delete_all_mip_starts().unwrap();
+        assert!(problem
+            .write_mip_starts(mst_path.to_str().unwrap())
+            .is_err());
+
+        problem.read_mip_starts(mst_path.to_str().unwrap()).unwrap();
+        let starts = problem.mip_starts().unwrap();
+        assert_eq!(starts.len(), 1);
+        assert_eq!(starts[0].name(), Some("start"));
+        assert_eq!(starts[0].values(), &[(vars[0], 1.0), (vars[1], 2.0)]);
+
+        let records = sol_file::read(&mst_path).unwrap();
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].solution_name.as_deref(), Some("start"));
+
+        std::fs::remove_file(sol_path).unwrap();
+        std::fs::remove_file(mst_path).unwrap();
+    }
+
+    #[test]
+    fn lp_basis() {
+        let new_problem = |name: &str| {
+            let env = Environment::new().unwrap();
+            let mut problem = Problem::new(env, name).unwrap();
+            let vars = problem
+                .add_variables(vec![
+                    Variable::new(VariableType::Continuous, 1.0, 0.0, 10.0, "x0"),
+                    Variable::new(VariableType::Continuous, 2.0, 0.0, 10.0, "x1"),
+                ])
+                .unwrap();
+            let c0 = problem
+                .add_constraint(Constraint::new(
+                    ConstraintType::GreaterThanEq,
+                    3.0,
+                    None,
+                    vec![(vars[0], 1.0), (vars[1], 1.0)],
+                ))
+                .unwrap();
+            (problem, vars, c0)
+        };
+        let (mut problem, vars, c0) = new_problem("lp_basis");
+
+        problem.solve_as(ProblemType::Linear).unwrap();
+        let mut basis = problem.basis().unwrap();
+        assert_eq!(basis.variable_status(vars[0]).unwrap(), BasisStatus::Basic);
+        assert_eq!(
+            basis.variable_status(vars[1]).unwrap(),
+            BasisStatus::AtLower
+        );
+        assert_eq!(basis.constraint_status(c0).unwrap(), BasisStatus::AtLower);
+
+        let path = temp_path("lp_basis.bas");
+        problem.write_basis(path.to_str().unwrap()).unwrap();
 
         let x2 = problem
             .add_variable(Variable::new(
                 VariableType::Continuous,
-                3.0,
+                0.5,
                 0.0,
-                INFINITY,
+                10.0,
                 "x2",
             ))
             .unwrap();
+        assert!(problem.set_basis(&basis).is_err());
+        assert!(basis.variable_status(x2).is_err());
+        basis.push_variable(BasisStatus::Basic);
+        basis.set_variable_status(x2, BasisStatus::AtLower).unwrap();
+        problem.set_basis(&basis).unwrap();
+
+        let mut other = Problem::new(Environment::new().unwrap(), "other").unwrap();
+        let y = other
+            .add_variable(Variable::new(VariableType::Continuous, 1.0, 0.0, 1.0, "y"))
+            .unwrap();
+        assert!(matches!(
+            basis.variable_status(y),
+            Err(errors::Error::Input(errors::Input::ForeignVariable {
+                index: 0
+            }))
+        ));
+        assert!(other.set_basis(&basis).is_err());
+
+        let solution = problem.solve_as(ProblemType::Linear).unwrap();
+        assert_eq!(solution.stats().simplex_iterations, 0);
+        assert_eq!(solution.variable_value(vars[0]), 3.0);
+        assert_eq!(solution.variable_value(x2), 0.0);
+        assert_eq!(problem.basis().unwrap(), basis);
+
+        // The file holds the basis of the problem before x2 was added
+        let (mut copy, vars, c0) = new_problem("lp_basis_copy");
+        copy.read_basis(path.to_str().unwrap()).unwrap();
+        std::fs::remove_file(path).unwrap();
+        let solution = copy.solve_as(ProblemType::Linear).unwrap();
+        assert_eq!(solution.stats().simplex_iterations, 0);
+        assert_eq!(solution.variable_value(vars[0]), 3.0);
+        let read = copy.basis().unwrap();
+        assert_eq!(read.variable_status(vars[0]).unwrap(), BasisStatus::Basic);
+        assert_eq!(read.variable_status(vars[1]).unwrap(), BasisStatus::AtLower);
+        assert_eq!(read.constraint_status(c0).unwrap(), BasisStatus::AtLower);
+    }
+
+    #[test]
+    fn lp_sensitivity_analysis() {
+        let env = Environment::new().unwrap();
+        let mut problem = Problem::new(env, "lp_sensitivity_analysis").unwrap();
+
+        let vars = problem
+            .add_variables(vec![
+                Variable::new(VariableType::Continuous, 1.0, 0.0, 10.0, "x0"),
+                Variable::new(VariableType::Continuous, 2.0, 0.0, 10.0, "x1"),
+            ])
+            .unwrap();
+        let c0 = problem
+            .add_constraint(Constraint::new(
+                ConstraintType::GreaterThanEq,
+                3.0,
+                None,
+                vec![(vars[0], 1.0), (vars[1], 1.0)],
+            ))
+            .unwrap();
+
+        assert!(matches!(
+            problem.objective_ranges(),
+            Err(errors::Error::Input(e))
+                if e.to_string().contains("without an optimal basic solution")
+        ));
+        problem.solve_as(ProblemType::Linear).unwrap();
+
+        let objective = problem.objective_ranges().unwrap();
+        assert_eq!(objective[0].0, vars[0]);
+        assert_eq!(objective[0].1.lower, 0.0);
+        assert_eq!(objective[0].1.upper, 2.0);
+        assert!(objective[1].1.contains(2.0));
+
+        let bounds = problem.bound_ranges().unwrap();
+        assert_eq!(bounds.len(), 2);
+        assert!(bounds[0].1.upper_bound.contains(10.0));
+
+        let rhs = problem.rhs_ranges().unwrap();
+        assert_eq!(rhs[0].0, c0);
+        assert_eq!(rhs[0].1.lower, 0.0);
+        assert_eq!(rhs[0].1.upper, 10.0);
+
+        problem.solve_as(ProblemType::MixedInteger).unwrap();
+        assert!(matches!(
+            problem.rhs_ranges(),
+            Err(errors::Error::Input(e)) if e.to_string().contains("not been solved as")
+        ));
+    }
+
+    #[test]
+    fn solution_quality() {
+        let mut env = Environment::new().unwrap();
+        env.set_parameter(parameters::mip::strategy::KappaStats::Full)
+            .unwrap();
+        let mut problem = Problem::new(env, "solution_quality").unwrap();
+
+        let vars = problem
+            .add_variables(vec![
+                Variable::new(VariableType::Integer, 1.0, 0.0, 10.0, "x0"),
+                Variable::new(VariableType::Integer, 2.0, 0.0, 10.0, "x1"),
+            ])
+            .unwrap();
+        problem
+            .add_constraint(Constraint::new(
+                ConstraintType::GreaterThanEq,
+                2.5,
+                None,
+                vec![(vars[0], 1.0), (vars[1], 1.0)],
+            ))
+            .unwrap();
+
+        assert!(problem.solution_quality().is_err());
+
+        problem.solve_as(ProblemType::Linear).unwrap();
+        let quality = problem.solution_quality().unwrap();
+        assert_eq!(quality.max_primal_infeasibility, 0.0);
+        assert_eq!(quality.max_dual_infeasibility, Some(0.0));
+        assert!(quality.kappa.unwrap() >= 1.0);
+        assert!(quality.mip_kappa.is_none());
+
+        problem.solve_as(ProblemType::MixedInteger).unwrap();
+        let quality = problem.solution_quality().unwrap();
+        assert_eq!(quality.max_integer_infeasibility, Some(0.0));
+        assert!(quality.max_dual_infeasibility.is_none());
+        assert!(quality.mip_kappa.is_some());
+    }
+
+    #[test]
+    fn unbounded_ray() {
+        let mut env = Environment::new().unwrap();
+        env.set_parameter(parameters::preprocessing::Presolve(false))
+            .unwrap();
+        env.set_parameter(parameters::lp_method::LPMethod::PrimalSimplex)
+            .unwrap();
+        let mut problem = Problem::new(env, "unbounded_ray").unwrap();
+
+        let vars = problem
+            .add_variables(vec![
+                Variable::new(VariableType::Continuous, 1.0, 0.0, INFINITY, "x0"),
+                Variable::new(VariableType::Continuous, 0.0, 0.0, 1.0, "x1"),
+            ])
+            .unwrap();
+        let problem = problem.set_objective_type(ObjectiveType::Maximize).unwrap();
+
+        assert!(matches!(
+            problem.solve_as(ProblemType::Linear),
+            Err(errors::Error::Cplex(errors::Cplex::Unbounded { .. }))
+        ));
 
-        let x3 = problem
-            .add_variable(Variable::new(VariableType::Integer, 1.0, 2.0, 3.0, "x3"))
-            .unwrap();
+        let ray = problem.unbounded_ray().unwrap();
+        assert_eq!(ray.len(), 1);
+        assert_eq!(ray[0].0, vars[0]);
+        assert!(ray[0].1 > 0.0);
+    }
 
-        assert_eq!(x0, VariableId(0));
-        assert_eq!(x1, VariableId(1));
-        assert_eq!(x2, VariableId(2));
-        assert_eq!(x3, VariableId(3));
+    #[test]
+    fn farkas_certificate() {
+        let mut env = Environment::new().unwrap();
+        env.set_parameter(parameters::preprocessing::Presolve(false))
+            .unwrap();
+        env.set_parameter(parameters::lp_method::LPMethod::DualSimplex)
+            .unwrap();
+        let mut problem = Problem::new(env, "farkas_certificate").unwrap();
 
+        let vars = problem
+            .add_variables(vec![
+                Variable::new(VariableType::Continuous, 1.0, 0.0, 10.0, "x0"),
+                Variable::new(VariableType::Continuous, 1.0, 0.0, 10.0, "x1"),
+            ])
+            .unwrap();
         let c0 = problem
             .add_constraint(Constraint::new(
-                ConstraintType::LessThanEq,
-                20.0,
+                ConstraintType::GreaterThanEq,
+                5.0,
                 None,
-                vec![(x0, -1.0), (x1, 1.0), (x2, 1.0), (x3, 10.0)],
+                vec![(vars[0], 1.0), (vars[1], 1.0)],
             ))
             .unwrap();
-
         let c1 = problem
             .add_constraint(Constraint::new(
                 ConstraintType::LessThanEq,
-                30.0,
+                3.0,
                 None,
-                vec![(x0, 1.0), (x1, -3.0), (x2, 1.0)],
+                vec![(vars[0], 1.0), (vars[1], 1.0)],
             ))
             .unwrap();
-
-        let c2 = problem
+        problem
             .add_constraint(Constraint::new(
-                ConstraintType::Eq,
-                0.0,
+                ConstraintType::LessThanEq,
+                10.0,
                 None,
-                vec![(x1, 1.0), (x3, -3.5)],
+                vec![(vars[0], 1.0)],
             ))
             .unwrap();
 
-        assert_eq!(c0, ConstraintId(0));
-        assert_eq!(c1, ConstraintId(1));
-        assert_eq!(c2, ConstraintId(2));
-
-        let problem = problem.set_objective_type(ObjectiveType::Maximize).unwrap();
-
-        let solution = problem.solve_as(ProblemType::MixedInteger).unwrap();
+        assert!(matches!(
+            problem.solve_as(ProblemType::Linear),
+            Err(errors::Error::Cplex(errors::Cplex::Unfeasible { .. }))
+        ));
 
-        assert_eq!(solution.objective_value(), 122.5);
+        let certificate = problem.farkas_certificate().unwrap();
+        let constraints = certificate
+            .multipliers()
+            .iter()
+            .map(|&(c, _)| c)
+            .collect::<Vec<_>>();
+        assert_eq!(constraints, vec![c0, c1]);
+        assert!(certificate.proof() > 0.0);
     }
 
     #[test]
-    fn mipex1_batch() {
+    fn model_query() {
         let env = Environment::new().unwrap();
-        let mut problem = Problem::new(env, "mipex1").unwrap();
+        let mut problem = Problem::new(env, "model_query").unwrap();
 
         let vars = problem
             .add_variables(vec![
-                Variable::new(VariableType::Continuous, 1.0, 0.0, 40.0, "x0"),
-                Variable::new(VariableType::Continuous, 2.0, 0.0, INFINITY, "x1"),
-                Variable::new(VariableType::Continuous, 3.0, 0.0, INFINITY, "x2"),
-                Variable::new(VariableType::Integer, 1.0, 2.0, 3.0, "x3"),
+                Variable::new(VariableType::Integer, 1.0, 0.0, 10.0, "x0"),
+                Variable::new(VariableType::Continuous, 2.0, -1.0, INFINITY, "x1"),
+                Variable::new(VariableType::Binary, 0.0, 0.0, 1.0, "x2"),
             ])
             .unwrap();
+        let c0 = problem
+            .add_constraint(Constraint::new(
+                ConstraintType::GreaterThanEq,
+                3.0,
+                Some("c0".to_owned()),
+                vec![(vars[0], 1.0), (vars[2], 2.0)],
+            ))
+            .unwrap();
+        problem
+            .add_constraint(Constraint::new(
+                ConstraintType::Eq,
+                1.0,
+                Some("c1".to_owned()),
+                vec![(vars[1], -1.0)],
+            ))
+            .unwrap();
+
+        assert_eq!(problem.num_variables(), 3);
+        assert_eq!(problem.num_constraints(), 2);
+        assert_eq!(problem.num_nonzeros(), 3);
 
         assert_eq!(
-            vars,
-            vec![VariableId(0), VariableId(1), VariableId(2), VariableId(3)]
+            problem.variable_names().unwrap(),
+            vec![
+                Some("x0".to_owned()),
+                Some("x1".to_owned()),
+                Some("x2".to_owned())
+            ]
+        );
+        assert_eq!(
+            problem.constraint_names().unwrap(),
+            vec![Some("c0".to_owned()), Some("c1".to_owned())]
+        );
+        assert_eq!(
+            problem.objective_coefficients().unwrap(),
+            vec![1.0, 2.0, 0.0]
+        );
+        assert_eq!(
+            problem.variable_bounds().unwrap(),
+            vec![(0.0, 10.0), (-1.0, INFINITY), (0.0, 1.0)]
+        );
+        assert_eq!(
+            problem.variable_types().unwrap(),
+            vec![
+                VariableType::Integer,
+                VariableType::Continuous,
+                VariableType::Binary
+            ]
+        );
+        assert_eq!(
+            problem.constraint_types().unwrap(),
+            vec![ConstraintType::GreaterThanEq, ConstraintType::Eq]
         );
+        assert_eq!(problem.constraint_rhs().unwrap(), vec![3.0, 1.0]);
+        assert_eq!(
+            problem.rows().unwrap(),
+            vec![vec![(vars[0], 1.0), (vars[2], 2.0)], vec![(vars[1], -1.0)]]
+        );
+        assert_eq!(problem.columns().unwrap()[2], vec![(c0, 2.0)]);
+    }
 
-        let cons = problem
+    #[test]
+    fn name_lookup() {
+        let env = Environment::new().unwrap();
+        let mut problem = Problem::new(env, "name_lookup").unwrap();
+
+        let vars = problem
+            .add_variables(vec![
+                Variable::new(VariableType::Continuous, 1.0, 0.0, 1.0, "x0"),
+                Variable::new(VariableType::Continuous, 1.0, 0.0, 1.0, "x1"),
+            ])
+            .unwrap();
+        let c0 = problem
+            .add_constraint(Constraint::new(
+                ConstraintType::LessThanEq,
+                1.0,
+                Some("c0".to_owned()),
+                vec![(vars[0], 1.0), (vars[1], 1.0)],
+            ))
+            .unwrap();
+
+        assert_eq!(problem.variable_by_name("x1"), Some(vars[1]));
+        assert_eq!(problem.variable_by_index(1), Some(vars[1]));
+        assert_eq!(problem.variable_by_index(2), None);
+        assert_eq!(problem.constraint_by_index(0), Some(c0));
+        assert_eq!(problem.variable_by_name("x2"), None);
+        assert_eq!(problem.constraint_by_name("c0"), Some(c0));
+
+        let x0 = problem
+            .add_variable(Variable::new(VariableType::Continuous, 1.0, 0.0, 1.0, "x0"))
+            .unwrap();
+        assert_eq!(problem.variable_by_name("x0"), Some(vars[0]));
+
+        problem.set_duplicate_name_policy(DuplicateNamePolicy::Error);
+        assert!(problem
+            .add_variable(Variable::new(VariableType::Continuous, 1.0, 0.0, 1.0, "x1"))
+            .is_err());
+        assert!(problem
             .add_constraints(vec![
                 Constraint::new(
                     ConstraintType::LessThanEq,
-                    20.0,
-                    None,
-                    vec![
-                        (vars[0], -1.0),
-                        (vars[1], 1.0),
-                        (vars[2], 1.0),
-                        (vars[3], 10.0),
-                    ],
+                    1.0,
+                    Some("c1".to_owned()),
+                    vec![(x0, 1.0)],
                 ),
                 Constraint::new(
                     ConstraintType::LessThanEq,
-                    30.0,
-                    None,
-                    vec![(vars[0], 1.0), (vars[1], -3.0), (vars[2], 1.0)],
-                ),
-                Constraint::new(
-                    ConstraintType::Eq,
-                    0.0,
-                    None,
-                    vec![(vars[1], 1.0), (vars[3], -3.5)],
+                    1.0,
+                    Some("c1".to_owned()),
+                    vec![(x0, 1.0)],
                 ),
             ])
+            .is_err());
+        assert_eq!(problem.num_variables(), 3);
+        assert_eq!(problem.num_constraints(), 1);
+    }
+
+    #[test]
+    fn foreign_ids() {
+        let env = std::rc::Rc::new(Environment::new().unwrap());
+        let mut first = Problem::new(std::rc::Rc::clone(&env), "first").unwrap();
+        let mut second = Problem::new(std::rc::Rc::clone(&env), "second").unwrap();
+
+        let x = first
+            .add_variable(Variable::new(VariableType::Continuous, 1.0, 0.0, 1.0, "x"))
+            .unwrap();
+        let y = second
+            .add_variable(Variable::new(VariableType::Continuous, 1.0, 0.0, 1.0, "y"))
+            .unwrap();
+        assert_eq!(x.into_inner(), y.into_inner());
+        assert_ne!(x, y);
+
+        assert!(matches!(
+            second.add_constraint(Constraint::new(
+                ConstraintType::LessThanEq,
+                1.0,
+                None,
+                vec![(x, 1.0)],
+            )),
+            Err(errors::Error::Input(_))
+        ));
+        assert!(second
+            .add_mip_starts(vec![MipStart::new(
+                MipStartEffort::Auto,
+                None,
+                vec![(x, 1.0)]
+            )])
+            .is_err());
+        assert_eq!(second.num_constraints(), 0);
+
+        let solution = second.solve_as(ProblemType::Linear).unwrap();
+        assert_eq!(solution.try_variable_value(y).unwrap(), 0.0);
+        assert!(matches!(
+            solution.try_variable_value(x),
+            Err(errors::Error::Input(_))
+        ));
+
+        assert!(matches!(
+            first.set_objective(ObjectiveType::Minimize, vec![(y, 1.0)]),
+            Err(errors::Error::Input(errors::Input::ForeignVariable {
+                index: 0
+            }))
+        ));
+    }
+
+    #[test]
+    fn input_validation() {
+        let env = Environment::new().unwrap();
+        let mut problem = Problem::new(env, "input_validation").unwrap();
+
+        let x0 = problem
+            .add_variable(Variable::new(VariableType::Binary, 1.0, 0.0, 1.0, "x0"))
+            .unwrap();
+
+        let err = problem
+            .add_variables(vec![
+                Variable::new(VariableType::Continuous, 1.0, 0.0, 1.0, "x1"),
+                Variable::new(VariableType::Binary, 1.0, 0.0, 2.0, "x2"),
+            ])
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            errors::Error::Input(errors::Input::Variable {
+                index: 2,
+                ref name,
+                reason: errors::InvalidValue::BinaryBounds { .. },
+            }) if name == "x2"
+        ));
+
+        let err = problem
+            .add_constraint(Constraint::new(
+                ConstraintType::LessThanEq,
+                1.0,
+                Some("c0".to_owned()),
+                vec![(x0, f64::NAN)],
+            ))
+            .unwrap_err();
+        assert!(err
+            .to_string()
+            .ends_with("constraint 0 (c0): coefficient of variable 0 is NaN"));
+
+        assert_eq!(problem.num_variables(), 1);
+        assert_eq!(problem.num_constraints(), 0);
+    }
+
+    #[test]
+    fn objective_and_mip_start_validation() {
+        let env = Environment::new().unwrap();
+        let mut problem = Problem::new(env, "objective_and_mip_start_validation").unwrap();
+
+        let x0 = problem
+            .add_variable(Variable::new(VariableType::Integer, 1.0, 0.0, 1.0, "x0"))
+            .unwrap();
+
+        let err = problem
+            .add_objective(Objective::new(0, 1.0, None, vec![(x0, f64::NAN)]))
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            errors::Error::Input(errors::Input::Variable {
+                index: 0,
+                reason: errors::InvalidValue::NaN { .. },
+                ..
+            })
+        ));
+
+        let err = problem
+            .add_objective(Objective::new(u32::MAX, 1.0, None, vec![(x0, 1.0)]))
+            .unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("priority 4294967295 is greater than"));
+
+        let err = problem
+            .add_mip_starts(vec![MipStart::new(
+                MipStartEffort::Auto,
+                None,
+                vec![(x0, 2.0 * INFINITY)],
+            )])
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            errors::Error::Input(errors::Input::Variable {
+                reason: errors::InvalidValue::BeyondInfinity { ref field, .. },
+                ..
+            }) if field == "MIP start value"
+        ));
+        assert!(problem.add_initial_soln(&[x0], &[f64::NAN]).is_err());
+        assert!(problem.mip_starts().unwrap().is_empty());
+    }
+
+    #[test]
+    fn load_sparse_matrix() {
+        let env = Environment::new().unwrap();
+        let problem = Problem::new(env, "load_sparse_matrix").unwrap();
+        let mut problem = problem.set_objective_type(ObjectiveType::Maximize).unwrap();
+
+        // max x0 + 2 x1 s.t. x0 + x1 <= 4, x1 <= 3
+        let matrix = SparseMatrix::new(&[0, 1, 3], &[0, 0, 1], &[1.0, 1.0, 1.0]).unwrap();
+        let (vars, cons) = problem
+            .load_matrix(
+                &matrix,
+                &[1.0, 2.0],
+                &[0.0, 0.0],
+                &[10.0, 10.0],
+                &[4.0, 3.0],
+                &[ConstraintType::LessThanEq, ConstraintType::LessThanEq],
+            )
             .unwrap();
+        assert_eq!(vars.len(), 2);
+        assert_eq!(cons.len(), 2);
+        assert_eq!(problem.num_nonzeros(), 3);
+        let err = problem
+            .load_matrix(
+                &matrix,
+                &[1.0, 2.0],
+                &[0.0; 2],
+                &[10.0; 2],
+                &[4.0, 3.0],
+                &[ConstraintType::Eq, ConstraintType::Eq],
+            )
+            .unwrap_err();
+        assert!(err.to_string().contains("already has variables"));
 
+        // x0 - x1 >= 0.5
+        let rows = SparseMatrix::new(&[0, 2], &[0, 1], &[1.0, -1.0]).unwrap();
+        let new = problem
+            .add_rows(&rows, &[0.5], &[ConstraintType::GreaterThanEq])
+            .unwrap();
+        assert_eq!(new[0].into_inner(), 2);
         assert_eq!(
-            cons,
-            vec![ConstraintId(0), ConstraintId(1), ConstraintId(2)]
+            problem.rows().unwrap()[2],
+            vec![(vars[0], 1.0), (vars[1], -1.0)]
         );
 
-        let problem = problem.set_objective_type(ObjectiveType::Maximize).unwrap();
-
-        let solution = problem.solve_as(ProblemType::MixedInteger).unwrap();
+        let invalid = SparseMatrix::new(&[0, 1], &[5], &[1.0]).unwrap();
+        assert!(problem
+            .add_rows(&invalid, &[0.0], &[ConstraintType::Eq])
+            .is_err());
 
-        assert_eq!(solution.objective_value(), 122.5);
+        let solution = problem.solve_as(ProblemType::Linear).unwrap();
+        assert_eq!(solution.variable_value(vars[0]), 2.25);
+        assert_eq!(solution.variable_value(vars[1]), 1.75);
     }
 
     #[test]
-    fn unfeasible() {
+    fn load_sparse_matrix_errors() {
         let env = Environment::new().unwrap();
-        let mut problem = Problem::new(env, "unfeasible").unwrap();
+        let mut problem = Problem::new(env, "load_sparse_matrix_errors").unwrap();
+        let matrix = SparseMatrix::new(&[0, 1, 3], &[0, 0, 1], &[1.0, 1.0, 1.0]).unwrap();
+
+        let err = problem
+            .load_matrix(
+                &matrix,
+                &[1.0, 2.0],
+                &[0.0; 2],
+                &[10.0; 2],
+                &[4.0, 3.0],
+                &[ConstraintType::Eq],
+            )
+            .unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("and 2 constraint types, got 2, 2, 2 and 1"));
+
+        let err = problem
+            .load_matrix(
+                &matrix,
+                &[1.0, 2.0],
+                &[0.0; 2],
+                &[10.0; 2],
+                &[4.0],
+                &[ConstraintType::Eq],
+            )
+            .unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("refers to constraints beyond the 1 given ones"));
+
+        // Ranged constraints need a range, which the bulk functions cannot take
+        let err = problem
+            .load_matrix(
+                &matrix,
+                &[1.0, 2.0],
+                &[0.0; 2],
+                &[10.0; 2],
+                &[4.0, 3.0],
+                &[ConstraintType::Eq, ConstraintType::Ranged],
+            )
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            errors::Error::Input(errors::Input::Constraint {
+                index: 1,
+                reason: errors::InvalidValue::MissingRange,
+                ..
+            })
+        ));
 
-        let vars = problem
+        assert_eq!(problem.num_variables(), 0);
+        assert_eq!(problem.num_constraints(), 0);
+    }
+
+    #[test]
+    fn problem_from_model() {
+        let mut model = Model::new("from_model");
+        let vars = model
             .add_variables(vec![
-                Variable::new(VariableType::Continuous, 1.0, 0.0, 1.0, "x0"),
-                Variable::new(VariableType::Continuous, 1.0, 0.0, 1.0, "x1"),
+                Variable::new(VariableType::Integer, 1.0, 0.0, 10.0, "x0"),
+                Variable::new(VariableType::Continuous, 2.0, 0.0, 10.0, "x1"),
             ])
             .unwrap();
+        model
+            .add_constraint(Constraint::new(
+                ConstraintType::LessThanEq,
+                3.5,
+                Some("c0".to_owned()),
+                vec![(vars[0], 1.0), (vars[1], 1.0)],
+            ))
+            .unwrap();
+        model.set_objective_type(ObjectiveType::Maximize);
 
-        assert_eq!(vars, vec![VariableId(0), VariableId(1)]);
+        let env = Environment::new().unwrap();
+        let problem = Problem::from_model(&env, &model).unwrap();
+        assert_eq!(problem.num_variables(), 2);
+        assert_eq!(problem.num_constraints(), 1);
+        let x1 = problem.variable_by_name("x1").unwrap();
+        assert_eq!(x1.into_inner(), 1);
 
-        let cons = problem
-            .add_constraints(vec![
-                Constraint::new(
-                    ConstraintType::Eq,
-                    0.0,
-                    None,
-                    vec![(vars[0], 1.0), (vars[1], 1.0)],
-                ),
-                Constraint::new(
-                    ConstraintType::Eq,
+        let solution = problem.solve_as(ProblemType::MixedInteger).unwrap();
+        assert_eq!(solution.variable_value(vars[0]), 0.0);
+        assert_eq!(solution.variable_value(vars[1]), 3.5);
+        assert_eq!(solution.variable_value(x1), 3.5);
+        assert_eq!(model.snapshot(&solution).unwrap().value("x1"), Some(3.5));
+
+        // Problems loaded from the same model diverge: the variables added to one of them,
+        // or to the model, do not belong to the others
+        let mut first = Problem::from_model(&env, &model).unwrap();
+        let mut second = Problem::from_model(&env, &model).unwrap();
+        let y = first
+            .add_variable(Variable::new(VariableType::Continuous, 1.0, 0.0, 1.0, "y"))
+            .unwrap();
+        let z = second
+            .add_variable(Variable::new(VariableType::Continuous, 1.0, 0.0, 1.0, "z"))
+            .unwrap();
+        let w = model
+            .add_variable(Variable::new(VariableType::Continuous, 1.0, 0.0, 1.0, "w"))
+            .unwrap();
+        for id in [y, w] {
+            assert!(matches!(
+                second.add_constraint(Constraint::new(
+                    ConstraintType::LessThanEq,
                     1.0,
                     None,
-                    vec![(vars[0], 1.0), (vars[1], 1.0)],
-                ),
-            ])
+                    vec![(id, 1.0)],
+                )),
+                Err(errors::Error::Input(errors::Input::ForeignVariable {
+                    index: 2
+                }))
+            ));
+        }
+        second
+            .add_constraint(Constraint::new(
+                ConstraintType::LessThanEq,
+                1.0,
+                None,
+                vec![(vars[0], 1.0), (z, 1.0)],
+            ))
             .unwrap();
 
-        assert_eq!(cons, vec![ConstraintId(0), ConstraintId(1)]);
+        let solution = first.solve_as(ProblemType::MixedInteger).unwrap();
+        assert_eq!(solution.variable_value(y), 1.0);
+        assert_eq!(solution.variable_value(vars[1]), 3.5);
+        assert!(solution.try_variable_value(z).is_err());
+        assert!(solution.try_variable_value(w).is_err());
+    }
 
-        let problem = problem.set_objective_type(ObjectiveType::Maximize).unwrap();
-        assert!(matches!(
-            problem.solve_as(ProblemType::Linear),
-            Err(errors::Error::Cplex(errors::Cplex::Unfeasible { .. }))
-        ));
+    #[test]
+    fn model_files_match_cplex() {
+        let model = file_model::fixtures::round_trip_model();
+        let problem = Problem::from_model(Environment::new().unwrap(), &model).unwrap();
+        let (lp, mps) = (temp_path("files.lp"), temp_path("files.mps"));
+        problem.write(lp.to_str().unwrap()).unwrap();
+        problem.write(mps.to_str().unwrap()).unwrap();
+
+        // The LP files must be identical, the MPS ones describe the same model
+        let written = std::fs::read_to_string(&lp).unwrap();
+        assert_eq!(lp_file::format(&model), written);
+        let read = mps_file::read(&mps).unwrap();
+        assert_eq!(mps_file::format(&model), mps_file::format(&read));
+
+        std::fs::remove_file(lp).unwrap();
+        std::fs::remove_file(mps).unwrap();
     }
 
     #[test]
-    fn unbounded() {
+    fn ranged_constraints() {
+        let model = lp_file::parse(
+            "maximize
+ obj: x + y
+subject to
+ r1: 1 <= x + y <= 3.5
+ c2: x - y = 0
+end
+",
+        )
+        .unwrap();
+
         let env = Environment::new().unwrap();
-        let mut problem = Problem::new(env, "unbounded").unwrap();
+        let problem = Problem::from_model(env, &model).unwrap();
+        assert_eq!(
+            problem.constraint_types().unwrap(),
+            vec![ConstraintType::Ranged, ConstraintType::Eq]
+        );
+        assert_eq!(problem.constraint_rhs().unwrap(), vec![1.0, 0.0]);
+        assert_eq!(problem.constraint_ranges().unwrap(), vec![2.5, 0.0]);
+
+        let x = model.variable_by_name("x").unwrap();
+        let solution = problem.solve_as(ProblemType::Linear).unwrap();
+        assert_eq!(solution.variable_value(x), 1.75);
+    }
 
+    #[test]
+    fn solution_names() {
+        let env = Environment::new().unwrap();
+        let mut problem = Problem::new(env, "solution_names").unwrap();
+        let vars = problem
+            .add_variables(vec![
+                Variable::new(VariableType::Continuous, 1.0, 0.0, 1.0, "x"),
+                Variable::new(VariableType::Continuous, 2.0, 0.0, 1.0, ""),
+            ])
+            .unwrap();
         problem
-            .add_variable(Variable::new(
-                VariableType::Integer,
-                1.0,
-                0.0,
-                INFINITY,
-                "x0",
+            .add_constraint(Constraint::new(
+                ConstraintType::GreaterThanEq,
+                0.5,
+                None,
+                vec![(vars[0], 1.0), (vars[1], 1.0)],
             ))
             .unwrap();
 
-        let problem = problem.set_objective_type(ObjectiveType::Maximize).unwrap();
+        let solution = problem.solve_as(ProblemType::Linear).unwrap();
+        assert_eq!(
+            solution.variable_names(),
+            &["x".to_owned(), "x2".to_owned()]
+        );
+        assert_eq!(solution.constraint_names(), &["c1".to_owned()]);
+        assert_eq!(solution.value_by_name("x"), Some(0.5));
+        assert_eq!(
+            solution.nonzero_values(1e-9).collect::<Vec<_>>(),
+            vec![("x", 0.5)]
+        );
 
-        assert!(matches!(
-            problem.solve_as(ProblemType::MixedInteger),
-            Err(errors::Error::Cplex(errors::Cplex::Unbounded { .. }))
-        ));
+        let mut csv = vec![];
+        solution.write_csv(&mut csv).unwrap();
+        assert_eq!(String::from_utf8(csv).unwrap(), "name,value\nx,0.5\nx2,0\n");
     }
 }
@@ -1,16 +1,35 @@
-use std::ffi::{c_char, c_void, CStr};
+#[cfg(feature = "cplex")]
+use std::{
+    ffi::CStr,
+    sync::{Arc, Mutex, PoisonError},
+};
+use std::{
+    ffi::{c_char, c_void},
+    time::SystemTime,
+};
+
+#[cfg(feature = "cplex")]
+use ffi::{cpxchannel, cpxenv, CPXdelfuncdest};
+#[cfg(feature = "cplex")]
+use log::error;
 
 pub type LoggingCallback = Option<unsafe extern "C" fn(*mut c_void, *const c_char)>;
 pub type LoggingClosure = Box<dyn Fn(&str) + Send>;
 
+#[cfg(feature = "cplex")]
 pub(crate) const RESULTS_STREAM_IDX: usize = 0;
+#[cfg(feature = "cplex")]
 pub(crate) const WARNING_STREAM_IDX: usize = 1;
+#[cfg(feature = "cplex")]
 pub(crate) const ERROR_STREAM_IDX: usize = 2;
+#[cfg(feature = "cplex")]
 pub(crate) const LOG_STREAM_IDX: usize = 3;
 
+#[cfg(feature = "cplex")]
 pub(crate) const DEFAULT_LOGGING_CLOSURE: Option<(LoggingClosure, LoggingCallback)> = None;
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum StreamType {
     Results,
     Warning,
@@ -18,7 +37,23 @@ pub enum StreamType {
     Log,
 }
 
+#[cfg(feature = "cplex")]
 impl StreamType {
+    pub(crate) const ALL: [StreamType; 4] = [
+        StreamType::Results,
+        StreamType::Warning,
+        StreamType::Error,
+        StreamType::Log,
+    ];
+
+    pub(crate) fn log_level(&self) -> log::Level {
+        match self {
+            Self::Results | Self::Log => log::Level::Info,
+            Self::Warning => log::Level::Warn,
+            Self::Error => log::Level::Error,
+        }
+    }
+
     pub(crate) fn as_index(&self) -> usize {
         match self {
             Self::Results => RESULTS_STREAM_IDX,
@@ -29,10 +64,12 @@ impl StreamType {
     }
 }
 
+#[cfg(feature = "cplex")]
 pub(crate) fn get_trampoline<F: Fn(&str)>() -> LoggingCallback {
     Some(trampoline::<F>)
 }
 
+#[cfg(feature = "cplex")]
 unsafe extern "C" fn trampoline<F>(user_data: *mut c_void, msg: *const c_char)
 where
     F: Fn(&str),
@@ -43,3 +80,63 @@ where
         logging_closure(line);
     }
 }
+
+/// A message emitted by CPLEX on one of its output streams
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LogMessage {
+    pub timestamp: SystemTime,
+    pub stream: StreamType,
+    pub message: String,
+}
+
+/// Captures the messages emitted on all the output streams of an environment, in addition
+/// to the destinations already registered on them. The destinations are removed on drop.
+#[cfg(feature = "cplex")]
+pub(crate) struct MessageCapture {
+    pub(crate) env: *const cpxenv,
+    pub(crate) destinations: Vec<(*mut cpxchannel, LoggingClosure, LoggingCallback)>,
+    pub(crate) messages: Arc<Mutex<Vec<LogMessage>>>,
+}
+
+#[cfg(feature = "cplex")]
+impl MessageCapture {
+    pub(crate) fn closure(&self, stream: StreamType) -> impl Fn(&str) + Send + 'static {
+        let messages = Arc::clone(&self.messages);
+        move |msg| {
+            // Called from the CPLEX callback, where a panic would abort the process
+            messages
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .push(LogMessage {
+                    timestamp: SystemTime::now(),
+                    stream,
+                    message: msg.to_owned(),
+                })
+        }
+    }
+
+    pub(crate) fn finish(self) -> Vec<LogMessage> {
+        let mut messages = self.messages.lock().unwrap_or_else(PoisonError::into_inner);
+        std::mem::take(&mut *messages)
+    }
+}
+
+#[cfg(feature = "cplex")]
+impl Drop for MessageCapture {
+    fn drop(&mut self) {
+        for (channel, mut closure, callback) in self.destinations.drain(..) {
+            let status = unsafe {
+                CPXdelfuncdest(
+                    self.env,
+                    channel,
+                    &mut *closure as *mut _ as *mut c_void,
+                    callback,
+                )
+            };
+            if status != 0 {
+                error!("Unable to remove message capture, got status: '{}'", status)
+            }
+        }
+    }
+}
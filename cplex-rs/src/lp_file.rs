@@ -0,0 +1,808 @@
+//! Pure rust reader and writer for the CPLEX LP file format.
+//!
+//! The output follows the layout of the file written by `Problem::write` with a `.lp`
+//! extension. The module does not call CPLEX, so it is also available without the `cplex`
+//! feature.
+//! <https://www.ibm.com/docs/en/icos/22.1.1?topic=cplex-lp-file-format-algebraic-representation>
+
+use std::{collections::HashMap, fmt::Write, path::Path};
+
+use crate::{
+    constants::INFINITY,
+    errors::{self, Result},
+    file_model::{clamp_infinity, format_number, FileModel, Row},
+    ConstraintType, Model, ObjectiveType, VariableType,
+};
+
+/// Length beyond which the expressions written to a file continue on the next line
+const LINE_LENGTH: usize = 79;
+
+/// Indentation of the continuation lines of an expression
+const CONTINUATION: &str = "     ";
+
+/// Read a model from the LP file at `path`.
+pub fn read<P: AsRef<Path>>(path: P) -> Result<Model> {
+    let content = std::fs::read_to_string(path)?;
+    parse(&content)
+}
+
+/// Parse a model from the content of an LP file.
+///
+/// The name of the model is taken from the `\Problem name:` comment written by CPLEX, if
+/// any. Quadratic terms, objective constants, SOS, lazy constraints and user cuts are not
+/// supported.
+pub fn parse(content: &str) -> Result<Model> {
+    let mut model = FileModel::new();
+    let mut section = None;
+    let mut tokens = vec![];
+
+    for (line, text) in content.lines().enumerate() {
+        let line = line + 1;
+        let (text, comment) = match text.split_once('\\') {
+            Some((text, comment)) => (text, Some(comment)),
+            None => (text, None),
+        };
+        if let Some(name) = comment.and_then(|c| c.trim().strip_prefix("Problem name:")) {
+            model.name = name.trim().to_owned();
+        }
+
+        let mut text = text;
+        if let Some((next, rest)) = section_keyword(text, line)? {
+            if let Some(current) = section {
+                parse_section(&mut model, current, Tokens::new(&tokens))?;
+            }
+            tokens.clear();
+            if next == Section::End {
+                section = None;
+                break;
+            }
+            section = Some(next);
+            text = rest;
+        }
+
+        if !text.trim().is_empty() {
+            if section.is_none() {
+                return Err(parse_error(line, "expected a section keyword"));
+            }
+            tokens.extend(tokenize(text, line)?);
+        }
+    }
+    if let Some(current) = section {
+        parse_section(&mut model, current, Tokens::new(&tokens))?;
+    }
+
+    model.into_model()
+}
+
+/// Write a model to the LP file at `path`.
+pub fn write<P: AsRef<Path>>(model: &Model, path: P) -> Result<()> {
+    Ok(std::fs::write(path, format(model))?)
+}
+
+/// Format a model in the LP file format.
+pub fn format(model: &Model) -> String {
+    let mut out = String::new();
+    // Writing to a String cannot fail
+    write_model(model, &mut out).unwrap();
+    out
+}
+
+fn write_model(model: &Model, out: &mut String) -> std::fmt::Result {
+    writeln!(out, "\\ENCODING=ISO-8859-1")?;
+    writeln!(out, "\\Problem name: {}", model.name())?;
+    writeln!(out)?;
+
+    match model.objective_type() {
+        ObjectiveType::Maximize => writeln!(out, "Maximize")?,
+        ObjectiveType::Minimize => writeln!(out, "Minimize")?,
+    }
+    let objective = model
+        .variables()
+        .iter()
+        .enumerate()
+        .filter(|(_, v)| v.weight() != 0.0)
+        .map(|(index, v)| (index, v.weight()));
+    write_wrapped(out, " obj1:", terms(model, objective))?;
+
+    writeln!(out, "Subject To")?;
+    for (index, c) in model.constraints().iter().enumerate() {
+        let weights = c.weights().iter().map(|&(v, w)| (v.into_inner(), w));
+        let mut row = terms(model, weights);
+        if row.is_empty() && !model.variables().is_empty() {
+            // An empty row still needs a term to be read back
+            row.push(format!("0 {}", model.variable_name(0)));
+        }
+        let rhs = format_number(c.rhs());
+        let head = match c.type_() {
+            ConstraintType::Ranged => format!(" {}: {rhs} <=", model.constraint_name(index)),
+            _ => format!(" {}:", model.constraint_name(index)),
+        };
+        row.push(match c.type_() {
+            ConstraintType::LessThanEq => format!("<= {rhs}"),
+            ConstraintType::Eq => format!("= {rhs}"),
+            ConstraintType::GreaterThanEq => format!(">= {rhs}"),
+            ConstraintType::Ranged => {
+                format!(
+                    "<= {}",
+                    format_number(c.rhs() + c.range().unwrap_or_default())
+                )
+            }
+        });
+        write_wrapped(out, &head, row)?;
+    }
+
+    let bounds = (0..model.variables().len())
+        .filter_map(|index| bound(model, index))
+        .collect::<Vec<_>>();
+    if !bounds.is_empty() {
+        writeln!(out, "Bounds")?;
+        for b in bounds {
+            writeln!(out, " {b}")?;
+        }
+    }
+
+    let section = |out: &mut String, title: &str, types: &[VariableType]| {
+        let names = model
+            .variables()
+            .iter()
+            .enumerate()
+            .filter(|(_, v)| types.contains(&v.type_()))
+            .map(|(index, _)| model.variable_name(index))
+            .collect::<Vec<_>>();
+        if names.is_empty() {
+            return Ok(());
+        }
+        writeln!(out, "{title}")?;
+        for name in names {
+            writeln!(out, " {name}")?;
+        }
+        Ok(())
+    };
+    section(out, "Binaries", &[VariableType::Binary])?;
+    section(
+        out,
+        "Generals",
+        &[VariableType::Integer, VariableType::SemiInteger],
+    )?;
+    section(
+        out,
+        "Semi-continuous",
+        &[VariableType::SemiContinuous, VariableType::SemiInteger],
+    )?;
+
+    writeln!(out, "End")
+}
+
+/// Format the terms of a linear expression, e.g. `["2 x1", "- x2", "+ 3.5 x3"]`
+fn terms(model: &Model, terms: impl Iterator<Item = (usize, f64)>) -> Vec<String> {
+    terms
+        .enumerate()
+        .map(|(position, (index, weight))| {
+            let name = model.variable_name(index);
+            let sign = match (position, weight < 0.0) {
+                (0, false) => "",
+                (_, false) => "+ ",
+                (_, true) => "- ",
+            };
+            if weight.abs() == 1.0 {
+                format!("{sign}{name}")
+            } else {
+                format!("{sign}{} {name}", format_number(weight.abs()))
+            }
+        })
+        .collect()
+}
+
+/// Write `head` followed by `pieces` separated by spaces, continuing on a new indented line
+/// before a line would grow beyond `LINE_LENGTH`, as CPLEX does for long rows.
+fn write_wrapped(out: &mut String, head: &str, pieces: Vec<String>) -> std::fmt::Result {
+    let mut line = head.to_owned();
+    for piece in pieces {
+        if line.len() + 1 + piece.len() > LINE_LENGTH && !line.trim().is_empty() {
+            writeln!(out, "{line}")?;
+            line = CONTINUATION.to_owned();
+        }
+        line.push(' ');
+        line.push_str(&piece);
+    }
+    writeln!(out, "{line}")
+}
+
+/// Format the bounds of a variable, if they differ from the default ones for its type
+fn bound(model: &Model, index: usize) -> Option<String> {
+    let var = &model.variables()[index];
+    let name = model.variable_name(index);
+    let (lower, upper) = (var.lower_bound(), var.upper_bound());
+    let default_upper = match var.type_() {
+        VariableType::Binary => 1.0,
+        _ => INFINITY,
+    };
+
+    if lower == 0.0 && upper == default_upper {
+        return None;
+    }
+    Some(if lower <= -INFINITY && upper >= INFINITY {
+        format!("{name} free")
+    } else if lower == upper {
+        format!("{name} = {}", format_number(lower))
+    } else if lower <= -INFINITY {
+        format!("-infinity <= {name} <= {}", format_number(upper))
+    } else if upper >= INFINITY {
+        format!("{name} >= {}", format_number(lower))
+    } else {
+        format!(
+            "{} <= {name} <= {}",
+            format_number(lower),
+            format_number(upper)
+        )
+    })
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum Section {
+    Objective(ObjectiveType),
+    Constraints,
+    Bounds,
+    Generals,
+    Binaries,
+    SemiContinuous,
+    End,
+}
+
+const KEYWORDS: &[(&str, Section)] = &[
+    ("maximize", Section::Objective(ObjectiveType::Maximize)),
+    ("maximum", Section::Objective(ObjectiveType::Maximize)),
+    ("max", Section::Objective(ObjectiveType::Maximize)),
+    ("minimize", Section::Objective(ObjectiveType::Minimize)),
+    ("minimum", Section::Objective(ObjectiveType::Minimize)),
+    ("min", Section::Objective(ObjectiveType::Minimize)),
+    ("subject to", Section::Constraints),
+    ("such that", Section::Constraints),
+    ("s.t.", Section::Constraints),
+    ("st.", Section::Constraints),
+    ("st", Section::Constraints),
+    ("bounds", Section::Bounds),
+    ("bound", Section::Bounds),
+    ("generals", Section::Generals),
+    ("general", Section::Generals),
+    ("gen", Section::Generals),
+    ("binaries", Section::Binaries),
+    ("binary", Section::Binaries),
+    ("bin", Section::Binaries),
+    ("semi-continuous", Section::SemiContinuous),
+    ("semis", Section::SemiContinuous),
+    ("semi", Section::SemiContinuous),
+    ("end", Section::End),
+];
+
+const UNSUPPORTED: &[&str] = &["sos", "lazy constraints", "user cuts"];
+
+/// Match a section keyword at the beginning of a line, returning the rest of the line
+fn section_keyword(text: &str, line: usize) -> Result<Option<(Section, &str)>> {
+    let text = text.trim_start();
+    let lower = text.to_ascii_lowercase();
+    let matches = |keyword: &str| {
+        lower
+            .strip_prefix(keyword)
+            .is_some_and(|rest| rest.is_empty() || rest.starts_with(char::is_whitespace))
+    };
+
+    if let Some(keyword) = UNSUPPORTED.iter().find(|k| matches(k)) {
+        return Err(parse_error(
+            line,
+            &format!("unsupported section '{keyword}'"),
+        ));
+    }
+    Ok(KEYWORDS
+        .iter()
+        .find(|(keyword, _)| matches(keyword))
+        .map(|(keyword, section)| (*section, &text[keyword.len()..])))
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum Sense {
+    Le,
+    Ge,
+    Eq,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Token {
+    Number(f64),
+    Name(String),
+    Plus,
+    Minus,
+    Colon,
+    Sense(Sense),
+}
+
+fn is_name_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || "!\"#$%&()/,.;?@_`'{}|~".contains(c)
+}
+
+fn tokenize(text: &str, line: usize) -> Result<Vec<(Token, usize)>> {
+    let bytes = text.as_bytes();
+    let mut tokens = vec![];
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let (start, c) = (i, bytes[i] as char);
+        let next = bytes.get(i + 1).map(|&b| b as char);
+        i += 1;
+        let token = match c {
+            c if c.is_ascii_whitespace() => continue,
+            '+' => Token::Plus,
+            '-' => Token::Minus,
+            ':' => Token::Colon,
+            '<' | '>' | '=' => {
+                let (sense, len) = match (c, next) {
+                    ('<', Some('=')) | ('=', Some('<')) => (Sense::Le, 2),
+                    ('>', Some('=')) | ('=', Some('>')) => (Sense::Ge, 2),
+                    ('<', _) => (Sense::Le, 1),
+                    ('>', _) => (Sense::Ge, 1),
+                    _ => (Sense::Eq, 1),
+                };
+                i = start + len;
+                Token::Sense(sense)
+            }
+            c if c.is_ascii_digit() || (c == '.' && next.is_some_and(|n| n.is_ascii_digit())) => {
+                while bytes
+                    .get(i)
+                    .is_some_and(|b| b.is_ascii_digit() || *b == b'.')
+                {
+                    i += 1;
+                }
+                // An exponent is only part of the number if followed by digits
+                if matches!(bytes.get(i), Some(b'e' | b'E')) {
+                    let digits = i + 1 + usize::from(matches!(bytes.get(i + 1), Some(b'+' | b'-')));
+                    if bytes.get(digits).is_some_and(u8::is_ascii_digit) {
+                        i = digits;
+                        while bytes.get(i).is_some_and(u8::is_ascii_digit) {
+                            i += 1;
+                        }
+                    }
+                }
+                let number = &text[start..i];
+                Token::Number(
+                    number
+                        .parse()
+                        .map_err(|_| parse_error(line, &format!("invalid number '{number}'")))?,
+                )
+            }
+            c if is_name_char(c) && c != '.' => {
+                while bytes.get(i).is_some_and(|&b| is_name_char(b as char)) {
+                    i += 1;
+                }
+                Token::Name(text[start..i].to_owned())
+            }
+            _ => {
+                let c = text[start..].chars().next().unwrap_or_default();
+                return Err(parse_error(line, &format!("unexpected character '{c}'")));
+            }
+        };
+        tokens.push((token, line));
+    }
+    Ok(tokens)
+}
+
+/// Cursor over the tokens of a section
+struct Tokens<'a> {
+    tokens: &'a [(Token, usize)],
+    position: usize,
+}
+
+impl<'a> Tokens<'a> {
+    fn new(tokens: &'a [(Token, usize)]) -> Self {
+        Self {
+            tokens,
+            position: 0,
+        }
+    }
+
+    fn peek(&self) -> Option<&'a Token> {
+        self.peek_at(0)
+    }
+
+    fn peek_at(&self, offset: usize) -> Option<&'a Token> {
+        self.tokens.get(self.position + offset).map(|(t, _)| t)
+    }
+
+    fn next(&mut self) -> Option<&'a Token> {
+        let token = self.peek();
+        self.position += 1;
+        token
+    }
+
+    fn error(&self, message: &str) -> errors::Error {
+        let line = self
+            .tokens
+            .get(self.position.min(self.tokens.len().saturating_sub(1)))
+            .map(|&(_, line)| line)
+            .unwrap_or_default();
+        parse_error(line, message)
+    }
+
+    /// Skip the `name:` label of a statement, if any
+    fn label(&mut self) -> Option<String> {
+        match (self.peek(), self.peek_at(1)) {
+            (Some(Token::Name(name)), Some(Token::Colon)) => {
+                self.position += 2;
+                Some(name.clone())
+            }
+            _ => None,
+        }
+    }
+
+    fn name(&mut self) -> Result<&'a str> {
+        match self.next() {
+            Some(Token::Name(name)) => Ok(name),
+            _ => Err(self.error("expected a variable name")),
+        }
+    }
+
+    fn sense(&mut self) -> Result<Sense> {
+        match self.next() {
+            Some(Token::Sense(sense)) => Ok(*sense),
+            _ => Err(self.error("expected <=, >= or =")),
+        }
+    }
+
+    /// A signed number or infinity at the current position, with the number of tokens it spans
+    fn constant_at(&self) -> Option<(f64, usize)> {
+        let mut sign = 1.0;
+        let mut len = 0;
+        loop {
+            match self.peek_at(len)? {
+                Token::Plus => {}
+                Token::Minus => sign = -sign,
+                Token::Number(value) => return Some((sign * value, len + 1)),
+                Token::Name(name)
+                    if name.eq_ignore_ascii_case("inf")
+                        || name.eq_ignore_ascii_case("infinity") =>
+                {
+                    return Some((sign * INFINITY, len + 1))
+                }
+                _ => return None,
+            }
+            len += 1;
+        }
+    }
+
+    fn constant(&mut self) -> Result<f64> {
+        let (value, len) = self
+            .constant_at()
+            .ok_or_else(|| self.error("expected a number"))?;
+        self.position += len;
+        Ok(clamp_infinity(value))
+    }
+
+    /// A constant followed by a sense, starting a ranged constraint or a bound
+    fn leading_constant(&mut self) -> Option<f64> {
+        let (value, len) = self.constant_at()?;
+        if !matches!(self.peek_at(len), Some(Token::Sense(_))) {
+            return None;
+        }
+        self.position += len;
+        Some(clamp_infinity(value))
+    }
+
+    /// A linear expression, as a list of variable names and coefficients
+    fn expression(&mut self) -> Result<Vec<(&'a str, f64)>> {
+        let mut terms = vec![];
+        loop {
+            let mut sign = 1.0;
+            let mut signed = false;
+            while let Some(Token::Plus | Token::Minus) = self.peek() {
+                if self.next() == Some(&Token::Minus) {
+                    sign = -sign;
+                }
+                signed = true;
+            }
+            if !terms.is_empty() && !signed {
+                return Ok(terms);
+            }
+            match (self.peek(), self.peek_at(1)) {
+                (Some(Token::Number(value)), Some(Token::Name(name))) => {
+                    self.position += 2;
+                    terms.push((name.as_str(), sign * value));
+                }
+                (Some(Token::Number(_)), _) => {
+                    return Err(self.error("constant terms are not supported"))
+                }
+                (Some(Token::Name(name)), _) => {
+                    self.position += 1;
+                    terms.push((name.as_str(), sign));
+                }
+                _ if signed => return Err(self.error("expected a term")),
+                _ => return Ok(terms),
+            }
+        }
+    }
+}
+
+fn parse_section(model: &mut FileModel, section: Section, mut tokens: Tokens) -> Result<()> {
+    match section {
+        Section::Objective(ty) => {
+            model.objective_type = ty;
+            tokens.label();
+            for (name, value) in tokens.expression()? {
+                let index = model.column(name);
+                model.columns[index].objective += value;
+            }
+            if tokens.peek().is_some() {
+                return Err(tokens.error("unexpected token in the objective"));
+            }
+        }
+        Section::Constraints => {
+            while tokens.peek().is_some() {
+                let name = tokens.label();
+                let row = if let Some(lower) = tokens.leading_constant() {
+                    let first = tokens.sense()?;
+                    let terms = tokens.expression()?;
+                    let second = tokens.sense()?;
+                    let upper = tokens.constant()?;
+                    let (lower, upper) = match (first, second) {
+                        (Sense::Le, Sense::Le) => (lower, upper),
+                        (Sense::Ge, Sense::Ge) => (upper, lower),
+                        _ => return Err(tokens.error("invalid ranged constraint")),
+                    };
+                    Row {
+                        name,
+                        type_: ConstraintType::Ranged,
+                        rhs: lower,
+                        range: upper - lower,
+                        terms: row_terms(model, terms),
+                    }
+                } else {
+                    let terms = tokens.expression()?;
+                    if terms.is_empty() {
+                        return Err(tokens.error("expected a term"));
+                    }
+                    let type_ = match tokens.sense()? {
+                        Sense::Le => ConstraintType::LessThanEq,
+                        Sense::Ge => ConstraintType::GreaterThanEq,
+                        Sense::Eq => ConstraintType::Eq,
+                    };
+                    Row {
+                        name,
+                        type_,
+                        rhs: tokens.constant()?,
+                        range: 0.0,
+                        terms: row_terms(model, terms),
+                    }
+                };
+                model.add_row(row);
+            }
+        }
+        Section::Bounds => {
+            while tokens.peek().is_some() {
+                if let Some(value) = tokens.leading_constant() {
+                    // `value <= x` is the same as `x >= value`
+                    let sense = match tokens.sense()? {
+                        Sense::Le => Sense::Ge,
+                        Sense::Ge => Sense::Le,
+                        Sense::Eq => Sense::Eq,
+                    };
+                    let index = model.column(tokens.name()?);
+                    set_bound(model, index, sense, value);
+                    if let Some(Token::Sense(_)) = tokens.peek() {
+                        let sense = tokens.sense()?;
+                        set_bound(model, index, sense, tokens.constant()?);
+                    }
+                } else {
+                    let index = model.column(tokens.name()?);
+                    match tokens.peek() {
+                        Some(Token::Name(free)) if free.eq_ignore_ascii_case("free") => {
+                            tokens.next();
+                            model.columns[index].lower = -INFINITY;
+                            model.columns[index].upper = INFINITY;
+                        }
+                        _ => {
+                            let sense = tokens.sense()?;
+                            set_bound(model, index, sense, tokens.constant()?);
+                        }
+                    }
+                }
+            }
+        }
+        Section::Generals | Section::Binaries | Section::SemiContinuous => {
+            while tokens.peek().is_some() {
+                let index = model.column(tokens.name()?);
+                let column = &mut model.columns[index];
+                column.type_ = match (section, column.type_) {
+                    (Section::Generals, VariableType::SemiContinuous)
+                    | (Section::SemiContinuous, VariableType::Integer) => VariableType::SemiInteger,
+                    (Section::Generals, _) => VariableType::Integer,
+                    (Section::SemiContinuous, _) => VariableType::SemiContinuous,
+                    _ => {
+                        column.lower = 0.0;
+                        column.upper = 1.0;
+                        VariableType::Binary
+                    }
+                };
+            }
+        }
+        Section::End => {}
+    }
+    Ok(())
+}
+
+/// Set the bound of a variable from a `x <sense> value` statement
+fn set_bound(model: &mut FileModel, index: usize, sense: Sense, value: f64) {
+    let column = &mut model.columns[index];
+    match sense {
+        Sense::Le => column.upper = value,
+        Sense::Ge => column.lower = value,
+        Sense::Eq => {
+            column.lower = value;
+            column.upper = value;
+        }
+    }
+}
+
+/// Resolve the variables of a constraint, summing the coefficients of repeated variables
+fn row_terms(model: &mut FileModel, terms: Vec<(&str, f64)>) -> Vec<(usize, f64)> {
+    let mut positions = HashMap::<usize, usize>::new();
+    let mut row: Vec<(usize, f64)> = vec![];
+    for (name, value) in terms {
+        let index = model.column(name);
+        match positions.get(&index) {
+            Some(&position) => row[position].1 += value,
+            None => {
+                positions.insert(index, row.len());
+                row.push((index, value));
+            }
+        }
+    }
+    row
+}
+
+fn parse_error(line: usize, message: &str) -> errors::Error {
+    errors::Input::from_message(format!("Invalid LP file, line {line}: {message}")).into()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        file_model::fixtures::{example_model, round_trip_model},
+        Constraint, Variable,
+    };
+
+    #[test]
+    fn format_model() {
+        let model = example_model();
+        assert_eq!(
+            format(&model),
+            "\\ENCODING=ISO-8859-1
+\\Problem name: example
+
+Maximize
+ obj1: x1 + 2 x2 - 3 x3
+Subject To
+ c1: - x1 + x2 + x3 <= 20
+ c2: x1 - 3.5 x4 >= -2.5
+Bounds
+ 0 <= x1 <= 40
+ x4 free
+Binaries
+ x3
+Generals
+ x2
+End
+"
+        );
+    }
+
+    #[test]
+    fn parse_model() {
+        let model = parse(
+            "\\Problem name: example
+maximize
+ obj: 2x1 + 3 x2 - x3 + x1
+subject to
+ c1: -x1 + x2 + 1.5e1 x3 <= 20
+ x1 - 3.5 x4 >= -2.5
+ r1: -2 <= x1 + x2 <= 5e0 c3: 3 x2 + x2 = 1
+bounds
+ x1 <= 40
+ -inf <= x2 <= 5
+ x4 free
+ 3 >= x5
+binaries
+ x3
+general
+ x5
+semi-continuous
+ x5
+end
+",
+        )
+        .unwrap();
+
+        assert_eq!(model.name(), "example");
+        assert_eq!(model.objective_type(), ObjectiveType::Maximize);
+
+        let vars = model.variables();
+        let names = vars.iter().map(|v| v.name()).collect::<Vec<_>>();
+        assert_eq!(names, vec!["x1", "x2", "x3", "x4", "x5"]);
+        assert_eq!(vars[0].weight(), 3.0);
+        assert_eq!(vars[0].upper_bound(), 40.0);
+        assert_eq!(
+            (vars[1].lower_bound(), vars[1].upper_bound()),
+            (-INFINITY, 5.0)
+        );
+        assert_eq!(vars[2].type_(), VariableType::Binary);
+        assert_eq!(
+            (vars[3].lower_bound(), vars[3].upper_bound()),
+            (-INFINITY, INFINITY)
+        );
+        assert_eq!(vars[4].type_(), VariableType::SemiInteger);
+        assert_eq!(vars[4].upper_bound(), 3.0);
+
+        let cons = model.constraints();
+        assert_eq!(cons.len(), 4);
+        assert_eq!(cons[0].name(), Some("c1"));
+        assert_eq!(cons[0].weights()[2].1, 15.0);
+        assert_eq!(cons[1].name(), None);
+        assert_eq!(cons[1].type_(), ConstraintType::GreaterThanEq);
+        assert_eq!(cons[2].type_(), ConstraintType::Ranged);
+        assert_eq!((cons[2].rhs(), cons[2].range()), (-2.0, Some(7.0)));
+        assert_eq!(cons[3].weights().len(), 1);
+        assert_eq!(cons[3].weights()[0].1, 4.0);
+
+        assert!(parse("minimize\n obj: x + 3\nend\n").is_err());
+        assert!(parse("minimize\n obj: [ x ^ 2 ]\nend\n").is_err());
+        assert!(parse("minimize\n x\nsos\nend\n").is_err());
+        assert!(parse(" x + y\n").is_err());
+    }
+
+    #[test]
+    fn round_trip() {
+        let model = round_trip_model();
+        let text = format(&model);
+        let parsed = parse(&text).unwrap();
+        assert_eq!(format(&parsed), text);
+        assert_eq!(parsed.variables()[0].type_(), VariableType::SemiInteger);
+        assert_eq!(parsed.variables()[3].type_(), VariableType::Binary);
+        assert_eq!(parsed.constraints()[0].range(), Some(4.0));
+    }
+
+    #[test]
+    fn wrap_long_rows() {
+        let mut model = Model::new("wrap");
+        let x = model
+            .add_variables(
+                (0..30)
+                    .map(|i| {
+                        Variable::new(
+                            VariableType::Continuous,
+                            1.0 / 3.0,
+                            0.0,
+                            INFINITY,
+                            format!("x{i}"),
+                        )
+                    })
+                    .collect(),
+            )
+            .unwrap();
+        model
+            .add_constraints(vec![Constraint::new(
+                ConstraintType::LessThanEq,
+                1e-12,
+                None,
+                x.iter().map(|&x| (x, -0.1 - 0.2)).collect(),
+            )])
+            .unwrap();
+
+        let text = format(&model);
+        assert!(text.lines().all(|line| line.len() <= LINE_LENGTH));
+        assert!(text.contains("\n c1: - 0.3 x0 - 0.3 x1 "));
+        assert!(text.contains("\n      + 0.333333333333333 x3 "));
+        assert!(text.contains("\n      - 0.3 x29 <= 1e-12\n"));
+
+        let parsed = parse(&text).unwrap();
+        assert_eq!(format(&parsed), text);
+        assert_eq!(parsed.constraints()[0].weights().len(), 30);
+    }
+}
@@ -0,0 +1,168 @@
+use std::{borrow::Cow, ffi::c_int};
+
+use crate::errors::{self, Result};
+
+/// A sparse matrix in compressed format, either column-wise (CSC) or row-wise (CSR).
+///
+/// The arrays are passed to CPLEX as they are, without any intermediate copy, see
+/// `Problem::load_matrix` and `Problem::add_rows`.
+#[derive(Clone, Debug)]
+pub struct SparseMatrix<'a> {
+    starts: Cow<'a, [c_int]>,
+    indices: &'a [c_int],
+    values: &'a [f64],
+}
+
+impl<'a> SparseMatrix<'a> {
+    /// Create a matrix from the usual compressed arrays.
+    ///
+    /// The nonzeros of the `k`-th column (or row) are `indices[starts[k]..starts[k + 1]]` and
+    /// `values[starts[k]..starts[k + 1]]`, so `starts` has one more element than the number
+    /// of columns (or rows) and ends with the number of nonzeros.
+    pub fn new(starts: &'a [c_int], indices: &'a [c_int], values: &'a [f64]) -> Result<Self> {
+        let matrix = Self {
+            starts: Cow::Borrowed(starts),
+            indices,
+            values,
+        };
+        matrix.check()?;
+        Ok(matrix)
+    }
+
+    /// Create a column-wise matrix from a CSC `sprs` matrix, see `Problem::load_matrix`.
+    #[cfg(feature = "sprs")]
+    pub fn from_sprs_csc(matrix: sprs::CsMatViewI<'a, f64, c_int>) -> Result<Self> {
+        if !matrix.is_csc() {
+            return Err(errors::Input::from_message(
+                "Expected a CSC matrix, got a CSR one".to_owned(),
+            )
+            .into());
+        }
+        Self::from_sprs(matrix)
+    }
+
+    /// Create a row-wise matrix from a CSR `sprs` matrix, see `Problem::add_rows`.
+    #[cfg(feature = "sprs")]
+    pub fn from_sprs_csr(matrix: sprs::CsMatViewI<'a, f64, c_int>) -> Result<Self> {
+        if !matrix.is_csr() {
+            return Err(errors::Input::from_message(
+                "Expected a CSR matrix, got a CSC one".to_owned(),
+            )
+            .into());
+        }
+        Self::from_sprs(matrix)
+    }
+
+    #[cfg(feature = "sprs")]
+    fn from_sprs(matrix: sprs::CsMatViewI<'a, f64, c_int>) -> Result<Self> {
+        let (indptr, indices, values) = matrix.into_raw_storage();
+        // Views on a part of a matrix keep the offsets of the whole matrix
+        let starts = match indptr.first() {
+            Some(&offset) if offset != 0 => Cow::Owned(indptr.iter().map(|s| s - offset).collect()),
+            _ => Cow::Borrowed(indptr),
+        };
+        let matrix = Self {
+            starts,
+            indices,
+            values,
+        };
+        matrix.check()?;
+        Ok(matrix)
+    }
+
+    /// Number of columns of a column-wise matrix, or of rows of a row-wise one
+    pub fn outer_len(&self) -> usize {
+        self.starts.len().saturating_sub(1)
+    }
+
+    /// Number of nonzero coefficients
+    pub fn nnz(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Largest inner index referenced by the matrix, if any
+    pub(crate) fn max_index(&self) -> Option<c_int> {
+        self.indices.iter().copied().max()
+    }
+
+    pub(crate) fn starts(&self) -> &[c_int] {
+        &self.starts
+    }
+
+    pub(crate) fn indices(&self) -> &[c_int] {
+        self.indices
+    }
+
+    pub(crate) fn values(&self) -> &[f64] {
+        self.values
+    }
+
+    /// Number of nonzeros of each column (or row)
+    pub(crate) fn counts(&self) -> Vec<c_int> {
+        self.starts.windows(2).map(|w| w[1] - w[0]).collect()
+    }
+
+    fn check(&self) -> Result<()> {
+        let error = |message: &str| -> Result<()> {
+            Err(errors::Input::from_message(format!("Invalid sparse matrix: {message}")).into())
+        };
+
+        if self.starts.is_empty() {
+            return error("starts must contain at least one element");
+        }
+        if self.indices.len() != self.values.len() {
+            return error("indices and values must have the same length");
+        }
+        if self.starts[0] != 0 {
+            return error("starts must begin with 0");
+        }
+        if self.starts.windows(2).any(|w| w[0] > w[1]) {
+            return error("starts must be non decreasing");
+        }
+        if self.starts[self.starts.len() - 1] as usize != self.values.len() {
+            return error("starts must end with the number of nonzeros");
+        }
+        if self.indices.iter().any(|&i| i < 0) {
+            return error("indices must be non negative");
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn sparse_matrix() {
+        let matrix = SparseMatrix::new(&[0, 2, 2, 3], &[0, 1, 1], &[1.0, 2.0, 3.0]).unwrap();
+        assert_eq!(matrix.outer_len(), 3);
+        assert_eq!(matrix.nnz(), 3);
+        assert_eq!(matrix.counts(), vec![2, 0, 1]);
+        assert_eq!(matrix.max_index(), Some(1));
+
+        assert!(SparseMatrix::new(&[], &[], &[]).is_err());
+        assert!(SparseMatrix::new(&[0, 2], &[0], &[1.0]).is_err());
+        assert!(SparseMatrix::new(&[0, 2, 1], &[0, 1], &[1.0, 2.0]).is_err());
+        assert!(SparseMatrix::new(&[0, 1], &[-1], &[1.0]).is_err());
+    }
+
+    #[cfg(feature = "sprs")]
+    #[test]
+    fn sparse_matrix_from_sprs() {
+        let csc = sprs::CsMatI::<f64, c_int>::new_csc(
+            (2, 3),
+            vec![0, 2, 2, 3],
+            vec![0, 1, 1],
+            vec![1.0, 2.0, 3.0],
+        );
+        let matrix = SparseMatrix::from_sprs_csc(csc.view()).unwrap();
+        assert_eq!(matrix.counts(), vec![2, 0, 1]);
+        assert!(SparseMatrix::from_sprs_csr(csc.view()).is_err());
+
+        let tail = csc.slice_outer(1..3);
+        let matrix = SparseMatrix::from_sprs_csc(tail).unwrap();
+        assert_eq!(matrix.starts(), &[0, 0, 1]);
+        assert_eq!(matrix.indices(), &[1]);
+    }
+}
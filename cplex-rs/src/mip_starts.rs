@@ -0,0 +1,101 @@
+use std::ffi::c_int;
+
+use ffi::{
+    CPX_MIPSTART_AUTO, CPX_MIPSTART_CHECKFEAS, CPX_MIPSTART_NOCHECK, CPX_MIPSTART_REPAIR,
+    CPX_MIPSTART_SOLVEFIXED, CPX_MIPSTART_SOLVEMIP,
+};
+
+use crate::{Solution, VariableId};
+
+/// Effort level used by CPLEX to process a MIP start.
+/// <https://www.ibm.com/docs/en/icos/22.1.1?topic=mip-starting-from-solution-starts>
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum MipStartEffort {
+    Auto,
+    CheckFeasibility,
+    SolveFixed,
+    SolveMip,
+    Repair,
+    NoCheck,
+}
+
+impl MipStartEffort {
+    pub(crate) fn into_raw(self) -> c_int {
+        (match self {
+            MipStartEffort::Auto => CPX_MIPSTART_AUTO,
+            MipStartEffort::CheckFeasibility => CPX_MIPSTART_CHECKFEAS,
+            MipStartEffort::SolveFixed => CPX_MIPSTART_SOLVEFIXED,
+            MipStartEffort::SolveMip => CPX_MIPSTART_SOLVEMIP,
+            MipStartEffort::Repair => CPX_MIPSTART_REPAIR,
+            MipStartEffort::NoCheck => CPX_MIPSTART_NOCHECK,
+        }) as c_int
+    }
+
+    pub(crate) fn from_raw(raw: c_int) -> Option<Self> {
+        match raw as u32 {
+            CPX_MIPSTART_AUTO => Some(MipStartEffort::Auto),
+            CPX_MIPSTART_CHECKFEAS => Some(MipStartEffort::CheckFeasibility),
+            CPX_MIPSTART_SOLVEFIXED => Some(MipStartEffort::SolveFixed),
+            CPX_MIPSTART_SOLVEMIP => Some(MipStartEffort::SolveMip),
+            CPX_MIPSTART_REPAIR => Some(MipStartEffort::Repair),
+            CPX_MIPSTART_NOCHECK => Some(MipStartEffort::NoCheck),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct MipStart {
+    values: Vec<(VariableId, f64)>,
+    effort: MipStartEffort,
+    name: Option<String>,
+}
+
+impl MipStart {
+    pub fn new(
+        effort: MipStartEffort,
+        name: Option<String>,
+        values: Vec<(VariableId, f64)>,
+    ) -> MipStart {
+        MipStart {
+            values,
+            effort,
+            name,
+        }
+    }
+
+    /// Create a MIP start assigning to every variable its value in a previous `Solution`.
+    pub fn from_solution(
+        solution: &Solution,
+        effort: MipStartEffort,
+        name: Option<String>,
+    ) -> MipStart {
+        let values = solution
+            .variable_values()
+            .iter()
+            .enumerate()
+            .map(|(index, &value)| {
+                (
+                    VariableId {
+                        index,
+                        problem: solution.problem(),
+                    },
+                    value,
+                )
+            })
+            .collect();
+        Self::new(effort, name, values)
+    }
+
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    pub fn values(&self) -> &[(VariableId, f64)] {
+        &self.values
+    }
+
+    pub fn effort(&self) -> MipStartEffort {
+        self.effort
+    }
+}
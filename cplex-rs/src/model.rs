@@ -0,0 +1,572 @@
+//! A pure rust representation of a linear or mixed integer problem.
+//!
+//! A `Model` can be built, inspected and tested without creating an `Environment`, then
+//! loaded at once into a `Problem` through `Problem::from_model`. With the default `cplex`
+//! feature disabled, the crate builds without a CPLEX installation and only provides this
+//! pure rust side.
+
+use std::{borrow::Cow, path::Path};
+
+use crate::{
+    errors::{self, Result},
+    lp_file, mps_file,
+    names::{self, NameIndex},
+    validation, Constraint, ConstraintId, DuplicateNamePolicy, ObjectiveType, ProblemToken,
+    Solution, SolutionSnapshot, Variable, VariableId,
+};
+
+#[cfg(feature = "serde")]
+use crate::ConstraintData;
+#[cfg(feature = "cplex")]
+use crate::ModelIds;
+
+/// A linear or mixed integer problem, independent from any CPLEX environment.
+///
+/// The ids returned by a model remain valid for the problems loaded from it, and for their
+/// solutions, as long as they were handed out before the problem was loaded.
+///
+/// With the `serde` feature, a model is serialized with its name, objective type, variables
+/// and constraints. A deserialized model is validated like a model built through
+/// `add_variables` and `add_constraints`, and hands out ids of its own.
+///
+/// # Example
+/// ```
+/// use cplex_rs::*;
+///
+/// let mut model = Model::new("my_model");
+/// let x = model.add_variable(Variable::new(VariableType::Continuous, 1.0, 0.0, 1.0, "x")).unwrap();
+/// model.add_constraint(Constraint::new(ConstraintType::LessThanEq, 0.5, None, vec![(x, 1.0)])).unwrap();
+/// model.set_objective_type(ObjectiveType::Maximize);
+///
+/// # #[cfg(feature = "cplex")] {
+/// let problem = Problem::from_model(Environment::new().unwrap(), &model).unwrap();
+/// let solution = problem.solve_as(ProblemType::Linear).unwrap();
+/// assert_eq!(solution.variable_value(x), 0.5);
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct Model {
+    name: String,
+    token: ProblemToken,
+    objective_type: ObjectiveType,
+    variables: Vec<Variable>,
+    constraints: Vec<Constraint>,
+    variable_index: NameIndex,
+    constraint_index: NameIndex,
+    duplicate_names: DuplicateNamePolicy,
+}
+
+impl Model {
+    pub fn new<S>(name: S) -> Self
+    where
+        S: AsRef<str>,
+    {
+        Self {
+            name: name.as_ref().to_owned(),
+            token: ProblemToken::new(),
+            objective_type: ObjectiveType::Minimize,
+            variables: vec![],
+            constraints: vec![],
+            variable_index: NameIndex::default(),
+            constraint_index: NameIndex::default(),
+            duplicate_names: DuplicateNamePolicy::default(),
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn objective_type(&self) -> ObjectiveType {
+        self.objective_type
+    }
+
+    /// Change the objective type. Default: `ObjectiveType::Minimize`.
+    pub fn set_objective_type(&mut self, ty: ObjectiveType) {
+        self.objective_type = ty;
+    }
+
+    /// Set the policy applied when adding a variable or a constraint with a name already used
+    /// in the model. Default: `DuplicateNamePolicy::Warn`.
+    pub fn set_duplicate_name_policy(&mut self, policy: DuplicateNamePolicy) {
+        self.duplicate_names = policy;
+    }
+
+    #[cfg(test)]
+    pub(crate) fn token(&self) -> ProblemToken {
+        self.token
+    }
+
+    #[cfg(feature = "cplex")]
+    pub(crate) fn ids(&self) -> ModelIds {
+        ModelIds {
+            token: self.token,
+            variables: self.variables.len(),
+            constraints: self.constraints.len(),
+        }
+    }
+
+    #[cfg(feature = "cplex")]
+    pub(crate) fn duplicate_name_policy(&self) -> DuplicateNamePolicy {
+        self.duplicate_names
+    }
+
+    /// Add a variable to the model.
+    ///
+    /// The id for the variable is returned.
+    pub fn add_variable(&mut self, var: Variable) -> Result<VariableId> {
+        Ok(self.add_variables(vec![var])?[0])
+    }
+
+    /// Add an array of variables to the model.
+    ///
+    /// The id for the variables are returned, in the same order they have been given in the input.
+    pub fn add_variables(&mut self, vars: Vec<Variable>) -> Result<Vec<VariableId>> {
+        for (idx, var) in vars.iter().enumerate() {
+            validation::validate_variable(self.variables.len() + idx, var)?;
+        }
+        self.variable_index.check(
+            vars.iter().map(|v| v.name()),
+            self.duplicate_names,
+            "variable",
+        )?;
+
+        let first = self.variables.len();
+        for (idx, var) in vars.iter().enumerate() {
+            self.variable_index.insert(var.name(), first + idx);
+        }
+        self.variables.extend(vars);
+        Ok((first..self.variables.len())
+            .map(|index| VariableId {
+                index,
+                problem: self.token,
+            })
+            .collect())
+    }
+
+    /// Add a constraint to the model.
+    ///
+    /// The id for the constraint is returned.
+    pub fn add_constraint(&mut self, constraint: Constraint) -> Result<ConstraintId> {
+        Ok(self.add_constraints(vec![constraint])?[0])
+    }
+
+    /// Add an array of constraints to the model.
+    ///
+    /// The id for the constraints are returned, in the same order they have been given in the input.
+    pub fn add_constraints(&mut self, con: Vec<Constraint>) -> Result<Vec<ConstraintId>> {
+        for (idx, c) in con.iter().enumerate() {
+            validation::validate_constraint(self.constraints.len() + idx, c, |v| {
+                self.owns_variable(v)
+            })?;
+        }
+        self.constraint_index.check(
+            con.iter().filter_map(|c| c.name()),
+            self.duplicate_names,
+            "constraint",
+        )?;
+
+        let first = self.constraints.len();
+        for (idx, c) in con.iter().enumerate() {
+            if let Some(name) = c.name() {
+                self.constraint_index.insert(name, first + idx);
+            }
+        }
+        self.constraints.extend(con);
+        Ok((first..self.constraints.len())
+            .map(|index| ConstraintId {
+                index,
+                problem: self.token,
+            })
+            .collect())
+    }
+
+    pub fn variables(&self) -> &[Variable] {
+        &self.variables
+    }
+
+    pub fn constraints(&self) -> &[Constraint] {
+        &self.constraints
+    }
+
+    /// Get a variable of the model, or an `errors::Input` error if the id belongs to
+    /// another model or problem.
+    pub fn variable(&self, id: VariableId) -> Result<&Variable> {
+        if !self.owns_variable(id) {
+            return Err(errors::Input::ForeignVariable { index: id.index }.into());
+        }
+        Ok(&self.variables[id.index])
+    }
+
+    /// Get a constraint of the model, or an `errors::Input` error if the id belongs to
+    /// another model or problem.
+    pub fn constraint(&self, id: ConstraintId) -> Result<&Constraint> {
+        if self.token != id.problem || id.index >= self.constraints.len() {
+            return Err(errors::Input::ForeignConstraint { index: id.index }.into());
+        }
+        Ok(&self.constraints[id.index])
+    }
+
+    /// Get the id of the first variable added to the model with the given name.
+    pub fn variable_by_name(&self, name: &str) -> Option<VariableId> {
+        self.variable_index.get(name).map(|index| VariableId {
+            index,
+            problem: self.token,
+        })
+    }
+
+    /// Get the id of the first constraint added to the model with the given name.
+    pub fn constraint_by_name(&self, name: &str) -> Option<ConstraintId> {
+        self.constraint_index.get(name).map(|index| ConstraintId {
+            index,
+            problem: self.token,
+        })
+    }
+
+    /// Get the id of the variable at position `index`, in the order the variables have been
+    /// added, e.g. to rebind the index of a serialized id.
+    pub fn variable_by_index(&self, index: usize) -> Option<VariableId> {
+        (index < self.variables.len()).then_some(VariableId {
+            index,
+            problem: self.token,
+        })
+    }
+
+    /// Get the id of the constraint at position `index`, in the order the constraints have
+    /// been added.
+    pub fn constraint_by_index(&self, index: usize) -> Option<ConstraintId> {
+        (index < self.constraints.len()).then_some(ConstraintId {
+            index,
+            problem: self.token,
+        })
+    }
+
+    /// Bind a deserialized constraint to the variables of this model, so that it can be added
+    /// to the model or to a problem loaded from it.
+    ///
+    /// Every variable index of the constraint must be the index of a variable of the model.
+    #[cfg(feature = "serde")]
+    pub fn bind_constraint(&self, constraint: ConstraintData) -> Result<Constraint> {
+        constraint.bind(|index| {
+            self.variable_by_index(index)
+                .ok_or_else(|| errors::Input::ForeignVariable { index }.into())
+        })
+    }
+
+    /// Bind a solution which does not come from this model, e.g. a deserialized one, so that
+    /// its values can be read with the ids of the model.
+    ///
+    /// The solution must have one value per variable, and the names it holds, if any, must
+    /// be the ones of the variables of the model.
+    pub fn bind_solution(&self, solution: Solution) -> Result<Solution> {
+        let names = (0..self.variables.len())
+            .map(|index| self.variable_name(index))
+            .collect::<Vec<_>>();
+        let values = solution.variable_values().len();
+        let named = solution.variable_names();
+        if values != names.len() || !(named.is_empty() || named.iter().eq(names.iter())) {
+            return Err(errors::Input::from_message(format!(
+                "The solution, with {values} values, does not match the {} variables of model {}",
+                names.len(),
+                self.name
+            ))
+            .into());
+        }
+        Ok(solution.bind(self.token))
+    }
+
+    /// Pair the values of a solution of the problem loaded from this model with the names
+    /// of the variables.
+    pub fn snapshot(&self, solution: &Solution) -> Result<SolutionSnapshot> {
+        if !solution.comes_from(self.token) {
+            return Err(errors::Input::from_message(format!(
+                "The solution does not come from model {}",
+                self.name
+            ))
+            .into());
+        }
+        let names = (0..self.variables.len())
+            .map(|index| self.variable_name(index))
+            .collect::<Vec<_>>();
+        solution.snapshot(&names)
+    }
+
+    /// Read a model from a file named `name`, in the LP or MPS format depending on its `.lp`
+    /// or `.mps` extension.
+    ///
+    /// The model can then be inspected, or loaded through `Problem::from_model`. Reading it
+    /// does not call CPLEX, and is available without the `cplex` feature.
+    pub fn read<S>(name: S) -> Result<Self>
+    where
+        S: AsRef<str>,
+    {
+        let path = Path::new(name.as_ref());
+        match path.extension().and_then(|e| e.to_str()) {
+            Some("lp") => lp_file::read(path),
+            Some("mps") => mps_file::read(path),
+            _ => Err(errors::Input::from_message(format!(
+                "Unsupported model file format: {}",
+                path.display()
+            ))
+            .into()),
+        }
+    }
+
+    /// Write the model to a file named `name`, in the LP or free MPS format depending on
+    /// its `.lp` or `.mps` extension.
+    ///
+    /// Unlike `Problem::write`, this does not call CPLEX, and is available without the
+    /// `cplex` feature.
+    pub fn write<S>(&self, name: S) -> Result<()>
+    where
+        S: AsRef<str>,
+    {
+        let path = Path::new(name.as_ref());
+        match path.extension().and_then(|e| e.to_str()) {
+            Some("lp") => lp_file::write(self, path),
+            Some("mps") => mps_file::write(self, path),
+            _ => Err(errors::Input::from_message(format!(
+                "Unsupported model file format: {}",
+                path.display()
+            ))
+            .into()),
+        }
+    }
+
+    /// Name of the variable at position `index` in the written files.
+    pub(crate) fn variable_name(&self, index: usize) -> Cow<'_, str> {
+        names::variable_name(self.variables[index].name(), index)
+    }
+
+    /// Name of the constraint at position `index` in the written files.
+    pub(crate) fn constraint_name(&self, index: usize) -> Cow<'_, str> {
+        names::constraint_name(self.constraints[index].name(), index)
+    }
+
+    fn owns_variable(&self, id: VariableId) -> bool {
+        self.token == id.problem && id.index < self.variables.len()
+    }
+}
+
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize)]
+#[serde(rename = "Model")]
+struct ModelRef<'a> {
+    name: &'a str,
+    objective_type: ObjectiveType,
+    variables: &'a [Variable],
+    constraints: &'a [Constraint],
+}
+
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+#[serde(rename = "Model")]
+struct ModelData {
+    name: String,
+    objective_type: ObjectiveType,
+    variables: Vec<Variable>,
+    constraints: Vec<ConstraintData>,
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Model {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        ModelRef {
+            name: &self.name,
+            objective_type: self.objective_type,
+            variables: &self.variables,
+            constraints: &self.constraints,
+        }
+        .serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Model {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let data = ModelData::deserialize(deserializer)?;
+        let mut model = Model::new(data.name);
+        model.set_objective_type(data.objective_type);
+        model
+            .add_variables(data.variables)
+            .map_err(serde::de::Error::custom)?;
+
+        let constraints = data
+            .constraints
+            .into_iter()
+            .map(|c| model.bind_constraint(c))
+            .collect::<Result<Vec<_>>>()
+            .map_err(serde::de::Error::custom)?;
+        model
+            .add_constraints(constraints)
+            .map_err(serde::de::Error::custom)?;
+        Ok(model)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{ConstraintType, VariableType};
+
+    #[test]
+    fn build_model() {
+        let mut model = Model::new("model");
+        let vars = model
+            .add_variables(vec![
+                Variable::new(VariableType::Continuous, 1.0, 0.0, 1.0, "x0"),
+                Variable::new(VariableType::Integer, 2.0, 0.0, 5.0, "x1"),
+            ])
+            .unwrap();
+        let c0 = model
+            .add_constraint(Constraint::new(
+                ConstraintType::LessThanEq,
+                4.0,
+                Some("c0".to_owned()),
+                vec![(vars[0], 1.0), (vars[1], 1.0)],
+            ))
+            .unwrap();
+
+        assert_eq!(model.variables().len(), 2);
+        assert_eq!(model.variable(vars[1]).unwrap().name(), "x1");
+        assert_eq!(model.constraint(c0).unwrap().rhs(), 4.0);
+        assert_eq!(model.variable_by_name("x0"), Some(vars[0]));
+        assert_eq!(model.constraint_by_name("c0"), Some(c0));
+
+        let mut other = Model::new("other");
+        assert!(other.variable(vars[0]).is_err());
+        assert!(other
+            .add_constraint(Constraint::new(
+                ConstraintType::Eq,
+                0.0,
+                None,
+                vec![(vars[0], 1.0)]
+            ))
+            .is_err());
+
+        model.set_duplicate_name_policy(DuplicateNamePolicy::Error);
+        assert!(model
+            .add_variable(Variable::new(VariableType::Binary, 0.0, 0.0, 1.0, "x0"))
+            .is_err());
+        assert!(model
+            .add_variable(Variable::new(VariableType::Binary, 0.0, 0.0, 2.0, "x2"))
+            .is_err());
+        assert_eq!(model.variables().len(), 2);
+
+        // Unnamed variables are not duplicates of each other
+        let unnamed = model
+            .add_variables(vec![
+                Variable::new(VariableType::Continuous, 0.0, 0.0, 1.0, ""),
+                Variable::new(VariableType::Continuous, 0.0, 0.0, 1.0, ""),
+            ])
+            .unwrap();
+        model
+            .add_variable(Variable::new(VariableType::Continuous, 0.0, 0.0, 1.0, ""))
+            .unwrap();
+        assert_eq!(model.variables().len(), 5);
+        assert_eq!(model.variable_by_name(""), None);
+        assert_eq!(model.variable_name(unnamed[1].into_inner()), "x4");
+    }
+
+    #[test]
+    fn solution_snapshot() {
+        let mut model = Model::new("model");
+        let vars = model
+            .add_variables(vec![
+                Variable::new(VariableType::Continuous, 1.0, 0.0, 1.0, "x"),
+                Variable::new(VariableType::Continuous, 1.0, 0.0, 1.0, ""),
+            ])
+            .unwrap();
+        let solution = Solution::new(model.token(), vec![0.5, 1.0], 1.5);
+
+        let snapshot = model.snapshot(&solution).unwrap();
+        assert_eq!(snapshot.objective_value, 1.5);
+        assert_eq!(
+            snapshot.variables,
+            vec![("x".to_owned(), 0.5), ("x2".to_owned(), 1.0)]
+        );
+        assert_eq!(snapshot.value("x"), Some(0.5));
+        assert_eq!(solution.variable_value(vars[1]), 1.0);
+
+        assert!(Model::new("other").snapshot(&solution).is_err());
+        assert!(solution.snapshot(&["x"]).is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip() {
+        let mut model = Model::new("model");
+        let vars = model
+            .add_variables(vec![
+                Variable::new(VariableType::Continuous, 1.0, 0.0, 1.0, "x0"),
+                Variable::new(VariableType::Integer, 2.0, 0.0, 5.0, "x1"),
+            ])
+            .unwrap();
+        model
+            .add_constraints(vec![
+                Constraint::new(
+                    ConstraintType::LessThanEq,
+                    4.0,
+                    Some("c0".to_owned()),
+                    vec![(vars[0], 1.0), (vars[1], 1.0)],
+                ),
+                Constraint::new_ranged(1.0, 2.0, None, vec![(vars[1], 1.0)]),
+            ])
+            .unwrap();
+        model.set_objective_type(ObjectiveType::Maximize);
+
+        let json = serde_json::to_string(&model).unwrap();
+        let constraint = serde_json::to_string(&model.constraints()[0]).unwrap();
+        let copy: Model = serde_json::from_str(&json).unwrap();
+        assert_eq!(copy.name(), "model");
+        assert_eq!(copy.objective_type(), ObjectiveType::Maximize);
+        assert_eq!(copy.variables()[1].type_(), VariableType::Integer);
+        assert_eq!(copy.constraints()[1].range(), Some(1.0));
+        assert_eq!(serde_json::to_string(&copy).unwrap(), json);
+
+        let x1 = copy.variable_by_name("x1").unwrap();
+        assert_eq!(copy.constraints()[0].weights()[1].0, x1);
+        assert!(model.variable(x1).is_err());
+        assert_eq!(copy.variable_by_index(1), Some(x1));
+        assert_eq!(copy.variable_by_index(2), None);
+        assert_eq!(copy.constraint_by_index(0), copy.constraint_by_name("c0"));
+        assert_eq!(copy.constraint_by_index(2), None);
+
+        let invalid = json.replace("[1,1.0]", "[7,1.0]");
+        assert!(serde_json::from_str::<Model>(&invalid).is_err());
+
+        let data: ConstraintData = serde_json::from_str(&constraint).unwrap();
+        let bound = copy.bind_constraint(data.clone()).unwrap();
+        assert_eq!(bound.weights()[1], (x1, 1.0));
+        assert_eq!(bound.name(), Some("c0"));
+        assert_eq!(bound.type_(), ConstraintType::LessThanEq);
+        assert!(Model::new("other").bind_constraint(data).is_err());
+
+        let solution = Solution::new(model.token(), vec![1.0, 3.0], 7.0);
+        let copy: Solution =
+            serde_json::from_str(&serde_json::to_string(&solution).unwrap()).unwrap();
+        assert_eq!(copy.variable_values(), &[1.0, 3.0]);
+        assert_eq!(copy.objective_value(), 7.0);
+        assert!(copy.try_variable_value(vars[1]).is_err());
+        assert!(Model::new("other").bind_solution(copy.clone()).is_err());
+        let bound = model.bind_solution(copy).unwrap();
+        assert_eq!(bound.variable_value(vars[1]), 3.0);
+
+        let snapshot = model.snapshot(&solution).unwrap();
+        let json = serde_json::to_string(&snapshot).unwrap();
+        assert_eq!(
+            json,
+            r#"{"objective_value":7.0,"variables":[["x0",1.0],["x1",3.0]]}"#
+        );
+        assert_eq!(
+            serde_json::from_str::<SolutionSnapshot>(&json).unwrap(),
+            snapshot
+        );
+    }
+}
@@ -0,0 +1,517 @@
+//! Pure rust reader and writer for the CPLEX free MPS file format.
+//!
+//! The output describes the same model as the file written by `Problem::write` with a `.mps`
+//! extension, with numbers formatted the same way, but one entry per line in the free
+//! layout. The module does not call CPLEX, so it is also available without the `cplex`
+//! feature.
+//! <https://www.ibm.com/docs/en/icos/22.1.1?topic=cplex-mps-file-format-industry-standard>
+
+use std::{collections::HashSet, fmt::Write, path::Path};
+
+use crate::{
+    constants::INFINITY,
+    errors::{self, Result},
+    file_model::{clamp_infinity, format_number, FileModel, Row},
+    ConstraintType, Model, ObjectiveType, VariableType,
+};
+
+/// Read a model from the MPS file at `path`.
+pub fn read<P: AsRef<Path>>(path: P) -> Result<Model> {
+    let content = std::fs::read_to_string(path)?;
+    parse(&content)
+}
+
+/// Parse a model from the content of a free or fixed MPS file.
+///
+/// Names must not contain spaces. The first `N` row is the objective, other `N` rows are
+/// ignored. Objective constants, quadratic terms, SOS and indicator constraints are not
+/// supported.
+pub fn parse(content: &str) -> Result<Model> {
+    let mut model = FileModel::new();
+    let mut section = "";
+    let mut objective = None;
+    let mut free_rows = HashSet::new();
+    let mut ranges = vec![];
+    let mut integer = false;
+
+    for (line, text) in content.lines().enumerate() {
+        let line = line + 1;
+        if text.trim().is_empty() || text.starts_with('*') {
+            continue;
+        }
+        let fields = text.split_whitespace().collect::<Vec<_>>();
+        let error = |message: &str| parse_error(line, message);
+
+        if !text.starts_with(char::is_whitespace) {
+            section = fields[0];
+            match section {
+                "NAME" => model.name = fields[1..].join(" "),
+                "OBJSENSE" if fields.len() > 1 => {
+                    model.objective_type = objective_sense(fields[1], line)?
+                }
+                "OBJSENSE" | "ROWS" | "COLUMNS" | "RHS" | "RANGES" | "BOUNDS" => {}
+                "ENDATA" => break,
+                _ => return Err(error(&format!("unsupported section '{section}'"))),
+            }
+            continue;
+        }
+
+        match section {
+            "OBJSENSE" => model.objective_type = objective_sense(fields[0], line)?,
+            "ROWS" => {
+                let [ty, name] = fields[..] else {
+                    return Err(error("expected a row type and a row name"));
+                };
+                let type_ = match ty {
+                    "N" if objective.is_none() => {
+                        objective = Some(name);
+                        continue;
+                    }
+                    "N" => {
+                        free_rows.insert(name);
+                        continue;
+                    }
+                    "L" => ConstraintType::LessThanEq,
+                    "G" => ConstraintType::GreaterThanEq,
+                    "E" => ConstraintType::Eq,
+                    _ => return Err(error(&format!("unknown row type '{ty}'"))),
+                };
+                model.add_row(Row {
+                    name: Some(name.to_owned()),
+                    type_,
+                    rhs: 0.0,
+                    range: 0.0,
+                    terms: vec![],
+                });
+            }
+            "COLUMNS" => {
+                if fields.get(1) == Some(&"'MARKER'") {
+                    integer = match fields.get(2) {
+                        Some(&"'INTORG'") => true,
+                        Some(&"'INTEND'") => false,
+                        _ => return Err(error("unknown marker")),
+                    };
+                    continue;
+                }
+                if fields.len() % 2 == 0 {
+                    return Err(error("expected a column name and pairs of rows and values"));
+                }
+                let index = model.column(fields[0]);
+                if integer {
+                    model.columns[index].type_ = VariableType::Integer;
+                }
+                for pair in fields[1..].chunks(2) {
+                    let value = number(pair[1], line)?;
+                    if Some(pair[0]) == objective {
+                        model.columns[index].objective += value;
+                    } else if !free_rows.contains(pair[0]) {
+                        let row = model
+                            .find_row(pair[0])
+                            .ok_or_else(|| error(&format!("unknown row '{}'", pair[0])))?;
+                        model.rows[row].terms.push((index, value));
+                    }
+                }
+            }
+            "RHS" | "RANGES" => {
+                // The name of the right hand side or range vector is optional
+                let pairs = &fields[fields.len() % 2..];
+                for pair in pairs.chunks(2) {
+                    let value = clamp_infinity(number(pair[1], line)?);
+                    if Some(pair[0]) == objective {
+                        return Err(error("objective constants are not supported"));
+                    } else if free_rows.contains(pair[0]) {
+                        continue;
+                    }
+                    let row = model
+                        .find_row(pair[0])
+                        .ok_or_else(|| error(&format!("unknown row '{}'", pair[0])))?;
+                    if section == "RHS" {
+                        model.rows[row].rhs = value;
+                    } else {
+                        ranges.push((row, value));
+                    }
+                }
+            }
+            "BOUNDS" => set_bound(&mut model, &fields, line)?,
+            _ => return Err(error("unexpected data line")),
+        }
+    }
+
+    for (index, value) in ranges {
+        let row = &mut model.rows[index];
+        let (lower, upper) = match row.type_ {
+            ConstraintType::LessThanEq => (row.rhs - value.abs(), row.rhs),
+            ConstraintType::GreaterThanEq => (row.rhs, row.rhs + value.abs()),
+            _ if value < 0.0 => (row.rhs + value, row.rhs),
+            _ => (row.rhs, row.rhs + value),
+        };
+        row.type_ = ConstraintType::Ranged;
+        row.rhs = lower;
+        row.range = upper - lower;
+    }
+
+    model.into_model()
+}
+
+/// Write a model to the MPS file at `path`.
+pub fn write<P: AsRef<Path>>(model: &Model, path: P) -> Result<()> {
+    Ok(std::fs::write(path, format(model))?)
+}
+
+/// Format a model in the free MPS file format.
+pub fn format(model: &Model) -> String {
+    let mut out = String::new();
+    // Writing to a String cannot fail
+    write_model(model, &mut out).unwrap();
+    out
+}
+
+fn write_model(model: &Model, out: &mut String) -> std::fmt::Result {
+    writeln!(out, "NAME {}", model.name())?;
+    if model.objective_type() == ObjectiveType::Maximize {
+        writeln!(out, "OBJSENSE")?;
+        writeln!(out, "    MAX")?;
+    }
+
+    writeln!(out, "ROWS")?;
+    writeln!(out, " N  obj1")?;
+    for (index, c) in model.constraints().iter().enumerate() {
+        let sense = match c.type_() {
+            ConstraintType::LessThanEq => 'L',
+            ConstraintType::Eq | ConstraintType::Ranged => 'E',
+            ConstraintType::GreaterThanEq => 'G',
+        };
+        writeln!(out, " {sense}  {}", model.constraint_name(index))?;
+    }
+
+    // The coefficients are stored by row, MPS files list them by column
+    let mut columns = vec![vec![]; model.variables().len()];
+    for (row, c) in model.constraints().iter().enumerate() {
+        for &(var, weight) in c.weights() {
+            columns[var.into_inner()].push((row, weight));
+        }
+    }
+
+    writeln!(out, "COLUMNS")?;
+    let mut in_marker = false;
+    for (index, var) in model.variables().iter().enumerate() {
+        let integer = matches!(
+            var.type_(),
+            VariableType::Binary | VariableType::Integer | VariableType::SemiInteger
+        );
+        if integer != in_marker {
+            let marker = if integer { "INTORG" } else { "INTEND" };
+            writeln!(out, "    MARKER  'MARKER'  '{marker}'")?;
+            in_marker = integer;
+        }
+
+        let name = model.variable_name(index);
+        if var.weight() != 0.0 || columns[index].is_empty() {
+            writeln!(out, "    {name}  obj1  {}", format_number(var.weight()))?;
+        }
+        for &(row, weight) in &columns[index] {
+            writeln!(
+                out,
+                "    {name}  {}  {}",
+                model.constraint_name(row),
+                format_number(weight)
+            )?;
+        }
+    }
+    if in_marker {
+        writeln!(out, "    MARKER  'MARKER'  'INTEND'")?;
+    }
+
+    writeln!(out, "RHS")?;
+    for (index, c) in model.constraints().iter().enumerate() {
+        if c.rhs() != 0.0 {
+            writeln!(
+                out,
+                "    rhs  {}  {}",
+                model.constraint_name(index),
+                format_number(c.rhs())
+            )?;
+        }
+    }
+
+    let ranges = model
+        .constraints()
+        .iter()
+        .enumerate()
+        .filter_map(|(index, c)| Some((index, c.range()?)))
+        .collect::<Vec<_>>();
+    if !ranges.is_empty() {
+        writeln!(out, "RANGES")?;
+        for (index, range) in ranges {
+            writeln!(
+                out,
+                "    rng  {}  {}",
+                model.constraint_name(index),
+                format_number(range)
+            )?;
+        }
+    }
+
+    let bounds = (0..model.variables().len())
+        .flat_map(|index| bounds(model, index))
+        .collect::<Vec<_>>();
+    if !bounds.is_empty() {
+        writeln!(out, "BOUNDS")?;
+        for b in bounds {
+            writeln!(out, " {b}")?;
+        }
+    }
+
+    writeln!(out, "ENDATA")
+}
+
+/// Format the bounds of a variable which differ from the default `[0, +inf]` ones, and the
+/// `BV` bound marking a binary variable with its default `[0, 1]` bounds
+fn bounds(model: &Model, index: usize) -> Vec<String> {
+    let var = &model.variables()[index];
+    let name = model.variable_name(index);
+    let (lower, upper) = (var.lower_bound(), var.upper_bound());
+
+    if var.type_() == VariableType::Binary && lower == 0.0 && upper == 1.0 {
+        return vec![format!("BV bnd  {name}")];
+    }
+    if matches!(
+        var.type_(),
+        VariableType::SemiContinuous | VariableType::SemiInteger
+    ) {
+        let mut bounds = vec![];
+        if lower != 0.0 {
+            bounds.push(format!("LO bnd  {name}  {}", format_number(lower)));
+        }
+        bounds.push(format!("SC bnd  {name}  {}", format_number(upper)));
+        return bounds;
+    }
+
+    if lower <= -INFINITY && upper >= INFINITY {
+        return vec![format!("FR bnd  {name}")];
+    }
+    if lower == upper {
+        return vec![format!("FX bnd  {name}  {}", format_number(lower))];
+    }
+    let mut bounds = vec![];
+    if lower <= -INFINITY {
+        bounds.push(format!("MI bnd  {name}"));
+    } else if lower != 0.0 {
+        bounds.push(format!("LO bnd  {name}  {}", format_number(lower)));
+    }
+    if upper < INFINITY {
+        bounds.push(format!("UP bnd  {name}  {}", format_number(upper)));
+    }
+    bounds
+}
+
+fn objective_sense(sense: &str, line: usize) -> Result<ObjectiveType> {
+    match sense {
+        "MAX" | "MAXIMIZE" => Ok(ObjectiveType::Maximize),
+        "MIN" | "MINIMIZE" => Ok(ObjectiveType::Minimize),
+        _ => Err(parse_error(
+            line,
+            &format!("unknown objective sense '{sense}'"),
+        )),
+    }
+}
+
+/// Apply a line of the `BOUNDS` section
+fn set_bound(model: &mut FileModel, fields: &[&str], line: usize) -> Result<()> {
+    let error = |message: &str| parse_error(line, message);
+
+    // The name of the bound vector is optional, and so is the value of some bound types
+    let (ty, name, value) = match fields {
+        [ty, _, name, value] => (*ty, *name, Some(*value)),
+        [ty, _, second] if model.find_column(second).is_some() => (*ty, *second, None),
+        [ty, name, value] => (*ty, *name, Some(*value)),
+        [ty, name] => (*ty, *name, None),
+        _ => return Err(error("expected a bound type, a column name and a value")),
+    };
+    let index = model
+        .find_column(name)
+        .ok_or_else(|| error(&format!("unknown column '{name}'")))?;
+    let value = value
+        .map(|v| number(v, line).map(clamp_infinity))
+        .transpose()?;
+    let required = || value.ok_or_else(|| error(&format!("missing value for bound {ty}")));
+
+    let column = &mut model.columns[index];
+    match ty {
+        "UP" => {
+            column.upper = required()?;
+            // As CPLEX does, a negative upper bound makes the default lower bound infinite
+            if column.upper < 0.0 && column.lower == 0.0 {
+                column.lower = -INFINITY;
+            }
+        }
+        "LO" => column.lower = required()?,
+        "FX" => {
+            column.lower = required()?;
+            column.upper = column.lower;
+        }
+        "FR" => {
+            column.lower = -INFINITY;
+            column.upper = INFINITY;
+        }
+        "MI" => column.lower = -INFINITY,
+        "PL" => column.upper = INFINITY,
+        "BV" => {
+            column.type_ = VariableType::Binary;
+            column.lower = 0.0;
+            column.upper = 1.0;
+        }
+        "LI" => {
+            column.type_ = VariableType::Integer;
+            column.lower = required()?;
+        }
+        "UI" => {
+            column.type_ = VariableType::Integer;
+            column.upper = required()?;
+        }
+        "SC" => {
+            column.type_ = match column.type_ {
+                VariableType::Integer => VariableType::SemiInteger,
+                _ => VariableType::SemiContinuous,
+            };
+            column.upper = value.unwrap_or(INFINITY);
+        }
+        _ => return Err(error(&format!("unknown bound type '{ty}'"))),
+    }
+    Ok(())
+}
+
+fn number(value: &str, line: usize) -> Result<f64> {
+    value
+        .parse()
+        .map_err(|_| parse_error(line, &format!("invalid number '{value}'")))
+}
+
+fn parse_error(line: usize, message: &str) -> errors::Error {
+    errors::Input::from_message(format!("Invalid MPS file, line {line}: {message}")).into()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::file_model::fixtures::{example_model, round_trip_model};
+
+    #[test]
+    fn format_model() {
+        let model = example_model();
+        assert_eq!(
+            format(&model),
+            "NAME example
+OBJSENSE
+    MAX
+ROWS
+ N  obj1
+ L  c1
+ G  c2
+COLUMNS
+    x1  obj1  1
+    x1  c1  -1
+    x1  c2  1
+    MARKER  'MARKER'  'INTORG'
+    x2  obj1  2
+    x2  c1  1
+    x3  obj1  -3
+    x3  c1  1
+    MARKER  'MARKER'  'INTEND'
+    x4  c2  -3.5
+RHS
+    rhs  c1  20
+    rhs  c2  -2.5
+BOUNDS
+ UP bnd  x1  40
+ BV bnd  x3
+ FR bnd  x4
+ENDATA
+"
+        );
+    }
+
+    #[test]
+    fn parse_model() {
+        let model = parse(
+            "* A comment
+NAME          example
+OBJSENSE
+    MAX
+ROWS
+ N  obj
+ L  c1
+ G  c2
+ E  c3
+ N  free
+COLUMNS
+    x1        obj       1.0        c1        -1
+    x1        c2        1          free      5
+    MARKER    'MARKER'  'INTORG'
+    x2        obj       2          c1        1
+    x2        c3        1
+    MARKER    'MARKER'  'INTEND'
+    x3        c3        1
+    x4        c3        1
+RHS
+    rhs       c1        20         c2        -2.5
+    rhs       c3        1
+RANGES
+    rng       c1        5          c3        -2
+BOUNDS
+ UP bnd       x1        40
+ MI bnd       x2
+ UP bnd       x2        -1
+ BV bnd       x3
+ SC bnd       x4        1e30
+ENDATA
+",
+        )
+        .unwrap();
+
+        assert_eq!(model.name(), "example");
+        assert_eq!(model.objective_type(), ObjectiveType::Maximize);
+
+        let vars = model.variables();
+        assert_eq!(vars.len(), 4);
+        assert_eq!(
+            (
+                vars[0].weight(),
+                vars[0].lower_bound(),
+                vars[0].upper_bound()
+            ),
+            (1.0, 0.0, 40.0)
+        );
+        assert_eq!(vars[1].type_(), VariableType::Integer);
+        assert_eq!(
+            (vars[1].lower_bound(), vars[1].upper_bound()),
+            (-INFINITY, -1.0)
+        );
+        assert_eq!(vars[2].type_(), VariableType::Binary);
+        assert_eq!(vars[3].type_(), VariableType::SemiContinuous);
+        assert_eq!(vars[3].upper_bound(), INFINITY);
+
+        let cons = model.constraints();
+        assert_eq!(cons.len(), 3);
+        assert_eq!(cons[0].type_(), ConstraintType::Ranged);
+        assert_eq!((cons[0].rhs(), cons[0].range()), (15.0, Some(5.0)));
+        assert_eq!(cons[1].type_(), ConstraintType::GreaterThanEq);
+        assert_eq!(cons[1].rhs(), -2.5);
+        assert_eq!(cons[1].weights().len(), 1);
+        assert_eq!((cons[2].rhs(), cons[2].range()), (-1.0, Some(2.0)));
+
+        assert!(parse("ROWS\n N obj\nCOLUMNS\n    x  c1  1\nENDATA\n").is_err());
+        assert!(parse("NAME x\nQUADOBJ\nENDATA\n").is_err());
+    }
+
+    #[test]
+    fn round_trip() {
+        let model = round_trip_model();
+        let text = format(&model);
+        let parsed = parse(&text).unwrap();
+        assert_eq!(format(&parsed), text);
+        assert_eq!(parsed.variables()[0].type_(), VariableType::SemiInteger);
+        assert_eq!(parsed.variables()[3].type_(), VariableType::Binary);
+        assert_eq!(parsed.constraints()[0].range(), Some(4.0));
+    }
+}
@@ -0,0 +1,139 @@
+use std::{
+    borrow::Cow,
+    collections::{HashMap, HashSet},
+};
+
+use log::warn;
+
+use crate::errors::{self, Result};
+
+/// Policy applied when a variable or constraint is added to a problem with a name
+/// which is already used by another variable or constraint.
+///
+/// Whatever the policy, name based lookups return the first variable or constraint
+/// added with a given name.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum DuplicateNamePolicy {
+    /// Reject the insertion with an `errors::Input` error
+    Error,
+    /// Log a warning and add the variable or constraint
+    #[default]
+    Warn,
+    /// Silently add the variable or constraint
+    Allow,
+}
+
+/// Index from the names of the variables or constraints of a problem to their position.
+///
+/// Empty names mark unnamed variables: they are neither indexed nor checked for duplicates.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct NameIndex {
+    positions: HashMap<String, usize>,
+}
+
+impl NameIndex {
+    /// Apply `policy` to the names about to be inserted, checking them against the names
+    /// already indexed and against each other.
+    pub(crate) fn check<'a, I>(
+        &self,
+        names: I,
+        policy: DuplicateNamePolicy,
+        kind: &str,
+    ) -> Result<()>
+    where
+        I: IntoIterator<Item = &'a str>,
+    {
+        if policy == DuplicateNamePolicy::Allow {
+            return Ok(());
+        }
+
+        let mut batch = HashSet::new();
+        for name in names {
+            if name.is_empty() || (!self.positions.contains_key(name) && batch.insert(name)) {
+                continue;
+            }
+
+            match policy {
+                DuplicateNamePolicy::Error => {
+                    return Err(errors::Input::from_message(format!(
+                        "Duplicate {kind} name '{name}'"
+                    ))
+                    .into())
+                }
+                DuplicateNamePolicy::Warn => warn!("Duplicate {kind} name '{name}'"),
+                DuplicateNamePolicy::Allow => {}
+            }
+        }
+        Ok(())
+    }
+
+    pub(crate) fn insert(&mut self, name: &str, position: usize) {
+        if name.is_empty() {
+            return;
+        }
+        self.positions.entry(name.to_owned()).or_insert(position);
+    }
+
+    pub(crate) fn get(&self, name: &str) -> Option<usize> {
+        self.positions.get(name).copied()
+    }
+}
+
+/// Name of the variable at position `index`, which defaults to `x<index + 1>` for unnamed
+/// variables, as CPLEX does.
+pub(crate) fn variable_name(name: &str, index: usize) -> Cow<'_, str> {
+    match name {
+        "" => Cow::Owned(format!("x{}", index + 1)),
+        name => Cow::Borrowed(name),
+    }
+}
+
+/// Name of the constraint at position `index`, which defaults to `c<index + 1>` for unnamed
+/// constraints, as CPLEX does.
+pub(crate) fn constraint_name(name: Option<&str>, index: usize) -> Cow<'_, str> {
+    match name {
+        Some(name) => Cow::Borrowed(name),
+        None => Cow::Owned(format!("c{}", index + 1)),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn duplicate_names() {
+        let mut index = NameIndex::default();
+        index.insert("x0", 0);
+        index.insert("x0", 1);
+        assert_eq!(index.get("x0"), Some(0));
+        assert_eq!(index.get("x1"), None);
+
+        assert!(index
+            .check(["x0"], DuplicateNamePolicy::Error, "variable")
+            .is_err());
+        assert!(index
+            .check(["x1", "x1"], DuplicateNamePolicy::Error, "variable")
+            .is_err());
+        assert!(index
+            .check(["x1", "x2"], DuplicateNamePolicy::Error, "variable")
+            .is_ok());
+        assert!(index
+            .check(["x0", "x1", "x1"], DuplicateNamePolicy::Warn, "variable")
+            .is_ok());
+        assert!(index
+            .check(["x0"], DuplicateNamePolicy::Allow, "variable")
+            .is_ok());
+    }
+
+    #[test]
+    fn empty_names() {
+        let mut index = NameIndex::default();
+        index.insert("", 0);
+        index.insert("", 1);
+        assert_eq!(index.get(""), None);
+        assert!(index
+            .check(["", "", "x0", ""], DuplicateNamePolicy::Error, "variable")
+            .is_ok());
+    }
+}
@@ -0,0 +1,71 @@
+use crate::VariableId;
+
+#[derive(Clone, Debug)]
+pub struct Objective {
+    coefficients: Vec<(VariableId, f64)>,
+    priority: u32,
+    weight: f64,
+    abs_tolerance: f64,
+    rel_tolerance: f64,
+    name: Option<String>,
+}
+
+impl Objective {
+    /// Create an objective for a multi-objective problem.
+    ///
+    /// CPLEX takes the priority as a C `int`, so priorities above `i32::MAX` are rejected
+    /// when the objective is added to a problem.
+    ///
+    /// Objectives with a higher `priority` are optimized first (lexicographic ordering),
+    /// while objectives sharing the same priority are blended together according to
+    /// their `weight`.
+    pub fn new(
+        priority: u32,
+        weight: f64,
+        name: Option<String>,
+        vars: Vec<(VariableId, f64)>,
+    ) -> Objective {
+        Objective {
+            coefficients: vars,
+            priority,
+            weight,
+            abs_tolerance: 0.0,
+            rel_tolerance: 0.0,
+            name,
+        }
+    }
+
+    /// Set the absolute and relative degradation tolerances allowed for this objective
+    /// when optimizing objectives with a lower priority.
+    pub fn with_tolerances(mut self, abs_tolerance: f64, rel_tolerance: f64) -> Objective {
+        self.abs_tolerance = abs_tolerance;
+        self.rel_tolerance = rel_tolerance;
+        self
+    }
+
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// Coefficients of the variables in the objective, as opposed to `weight`, the weight of
+    /// the whole objective in a blend.
+    pub fn coefficients(&self) -> &[(VariableId, f64)] {
+        &self.coefficients
+    }
+
+    pub fn priority(&self) -> u32 {
+        self.priority
+    }
+
+    pub fn weight(&self) -> f64 {
+        self.weight
+    }
+
+    pub fn abs_tolerance(&self) -> f64 {
+        self.abs_tolerance
+    }
+
+    pub fn rel_tolerance(&self) -> f64 {
+        self.rel_tolerance
+    }
+}
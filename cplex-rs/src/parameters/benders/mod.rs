@@ -0,0 +1,65 @@
+pub mod tolerances;
+
+use ffi::{CPXPARAM_Benders_Strategy, CPXPARAM_Benders_WorkerAlgorithm};
+
+use crate::parameters::{private, Parameter, ParameterValue};
+
+impl private::Parameter for Strategy {}
+impl private::Parameter for WorkerAlgorithm {}
+
+/// Benders strategy.
+/// <https://www.ibm.com/docs/en/icos/22.1.1?topic=parameters-benders-strategy>
+///
+/// When using `Strategy::User`, the decomposition is taken from the partitions
+/// set through `Problem::set_benders_partitions`.
+#[derive(Copy, Clone, Debug)]
+pub enum Strategy {
+    Off,
+    Automatic,
+    User,
+    Workers,
+    Full,
+}
+
+impl Parameter for Strategy {
+    fn value(&self) -> ParameterValue {
+        ParameterValue::Integer(match self {
+            Self::Off => -1,
+            Self::Automatic => 0,
+            Self::User => 1,
+            Self::Workers => 2,
+            Self::Full => 3,
+        })
+    }
+
+    fn id(&self) -> u32 {
+        CPXPARAM_Benders_Strategy
+    }
+}
+
+/// Benders worker algorithm.
+/// <https://www.ibm.com/docs/en/icos/22.1.1?topic=parameters-benders-worker-algorithm>
+#[derive(Copy, Clone, Debug)]
+pub enum WorkerAlgorithm {
+    Automatic,
+    PrimalSimplex,
+    DualSimplex,
+    NetworkSimplex,
+    Barrier,
+}
+
+impl Parameter for WorkerAlgorithm {
+    fn value(&self) -> ParameterValue {
+        ParameterValue::Integer(match self {
+            Self::Automatic => 0,
+            Self::PrimalSimplex => 1,
+            Self::DualSimplex => 2,
+            Self::NetworkSimplex => 3,
+            Self::Barrier => 4,
+        })
+    }
+
+    fn id(&self) -> u32 {
+        CPXPARAM_Benders_WorkerAlgorithm
+    }
+}
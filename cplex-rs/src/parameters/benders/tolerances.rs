@@ -0,0 +1,61 @@
+use ffi::{CPXPARAM_Benders_Tolerances_FeasibilityCut, CPXPARAM_Benders_Tolerances_OptimalityCut};
+
+use crate::errors::{self, Result};
+use crate::parameters::{private, Parameter, ParameterValue};
+
+impl private::Parameter for FeasibilityCut {}
+impl private::Parameter for OptimalityCut {}
+
+/// Benders feasibility cut violation tolerance.
+/// <https://www.ibm.com/docs/en/icos/22.1.1?topic=parameters-benders-feasibility-cut-violation-tolerance>
+#[derive(Copy, Clone, Debug)]
+pub struct FeasibilityCut(f64);
+
+impl FeasibilityCut {
+    pub fn new(value: f64) -> Result<Self> {
+        if !(1e-9..=1e-1).contains(&value) {
+            return Err(errors::Input::from_message(
+                "CPXPARAM_Benders_Tolerances_FeasibilityCut cannot be < 1e-9 or > 1e-1".to_string(),
+            )
+            .into());
+        }
+        Ok(Self(value))
+    }
+}
+
+impl Parameter for FeasibilityCut {
+    fn value(&self) -> ParameterValue {
+        ParameterValue::Double(self.0)
+    }
+
+    fn id(&self) -> u32 {
+        CPXPARAM_Benders_Tolerances_FeasibilityCut
+    }
+}
+
+/// Benders optimality cut violation tolerance.
+/// <https://www.ibm.com/docs/en/icos/22.1.1?topic=parameters-benders-optimality-cut-violation-tolerance>
+#[derive(Copy, Clone, Debug)]
+pub struct OptimalityCut(f64);
+
+impl OptimalityCut {
+    pub fn new(value: f64) -> Result<Self> {
+        if !(1e-9..=1e-1).contains(&value) {
+            return Err(errors::Input::from_message(
+                "CPXPARAM_Benders_Tolerances_OptimalityCut cannot be < 1e-9 or > 1e-1".to_string(),
+            )
+            .into());
+        }
+        Ok(Self(value))
+    }
+}
+
+impl Parameter for OptimalityCut {
+    fn value(&self) -> ParameterValue {
+        ParameterValue::Double(self.0)
+    }
+
+    fn id(&self) -> u32 {
+        CPXPARAM_Benders_Tolerances_OptimalityCut
+    }
+}
@@ -0,0 +1,36 @@
+use ffi::CPXPARAM_LPMethod;
+
+use super::{private, Parameter, ParameterValue};
+
+impl private::Parameter for LPMethod {}
+
+/// Algorithm for continuous problems.
+/// <https://www.ibm.com/docs/en/icos/22.1.1?topic=parameters-algorithm-continuous-problems>
+#[derive(Copy, Clone, Debug)]
+pub enum LPMethod {
+    Automatic,
+    PrimalSimplex,
+    DualSimplex,
+    NetworkSimplex,
+    Barrier,
+    Sifting,
+    Concurrent,
+}
+
+impl Parameter for LPMethod {
+    fn value(&self) -> ParameterValue {
+        ParameterValue::Integer(match self {
+            Self::Automatic => 0,
+            Self::PrimalSimplex => 1,
+            Self::DualSimplex => 2,
+            Self::NetworkSimplex => 3,
+            Self::Barrier => 4,
+            Self::Sifting => 5,
+            Self::Concurrent => 6,
+        })
+    }
+
+    fn id(&self) -> u32 {
+        CPXPARAM_LPMethod
+    }
+}
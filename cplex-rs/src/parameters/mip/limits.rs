@@ -1,15 +1,18 @@
 use ffi::{
-    CPXPARAM_MIP_Limits_AggForCut, CPXPARAM_MIP_Limits_Nodes, CPXPARAM_MIP_Limits_Solutions,
+    CPXPARAM_MIP_Limits_AggForCut, CPXPARAM_MIP_Limits_CutPasses, CPXPARAM_MIP_Limits_Nodes,
+    CPXPARAM_MIP_Limits_Solutions, CPXPARAM_MIP_Limits_TreeMemory,
 };
 
 use crate::{
     errors::{self, Result},
-    parameters::{private, Parameter, ParameterValue},
+    parameters::{private, MemorySize, Parameter, ParameterValue},
 };
 
 impl private::Parameter for AggForCut {}
+impl private::Parameter for CutPasses {}
 impl private::Parameter for Solutions {}
 impl private::Parameter for Nodes {}
+impl private::Parameter for TreeMemory {}
 
 /// AggForCut.
 /// <https://www.ibm.com/docs/en/icos/22.1.1?topic=parameters-constraint-aggregation-limit-cut-generation>
@@ -26,6 +29,30 @@ impl Parameter for AggForCut {
     }
 }
 
+/// Number of cutting plane passes.
+/// <https://www.ibm.com/docs/en/icos/22.1.1?topic=parameters-number-cutting-plane-passes>
+#[derive(Copy, Clone, Debug)]
+pub enum CutPasses {
+    /// Do not generate cuts
+    None,
+    Automatic,
+    Passes(u32),
+}
+
+impl Parameter for CutPasses {
+    fn value(&self) -> ParameterValue {
+        ParameterValue::Long(match self {
+            Self::None => -1,
+            Self::Automatic => 0,
+            Self::Passes(passes) => *passes as i64,
+        })
+    }
+
+    fn id(&self) -> u32 {
+        CPXPARAM_MIP_Limits_CutPasses
+    }
+}
+
 /// Solutions.
 /// <https://www.ibm.com/docs/en/icos/22.1.1?topic=parameters-mip-integer-solution-limit>
 #[derive(Copy, Clone, Debug)]
@@ -68,3 +95,18 @@ impl Parameter for Nodes {
         CPXPARAM_MIP_Limits_Nodes
     }
 }
+
+/// Tree memory limit.
+/// <https://www.ibm.com/docs/en/icos/22.1.1?topic=parameters-tree-memory-limit>
+#[derive(Copy, Clone, Debug)]
+pub struct TreeMemory(pub MemorySize);
+
+impl Parameter for TreeMemory {
+    fn value(&self) -> ParameterValue {
+        ParameterValue::Double(self.0.as_megabytes())
+    }
+
+    fn id(&self) -> u32 {
+        CPXPARAM_MIP_Limits_TreeMemory
+    }
+}
@@ -0,0 +1,88 @@
+use ffi::{
+    CPXPARAM_MIP_Strategy_File, CPXPARAM_MIP_Strategy_HeuristicFreq,
+    CPXPARAM_MIP_Strategy_KappaStats, CPX_MIPKAPPA_AUTO, CPX_MIPKAPPA_FULL, CPX_MIPKAPPA_OFF,
+    CPX_MIPKAPPA_SAMPLE,
+};
+
+use crate::parameters::{private, Parameter, ParameterValue};
+
+impl private::Parameter for File {}
+
+/// Node storage file switch.
+/// <https://www.ibm.com/docs/en/icos/22.1.1?topic=parameters-node-storage-file-switch>
+#[derive(Copy, Clone, Debug)]
+pub enum File {
+    NoNodeFile,
+    InMemoryCompressed,
+    OnDisk,
+    OnDiskCompressed,
+}
+
+impl Parameter for File {
+    fn value(&self) -> ParameterValue {
+        ParameterValue::Integer(match self {
+            Self::NoNodeFile => 0,
+            Self::InMemoryCompressed => 1,
+            Self::OnDisk => 2,
+            Self::OnDiskCompressed => 3,
+        })
+    }
+
+    fn id(&self) -> u32 {
+        CPXPARAM_MIP_Strategy_File
+    }
+}
+
+impl private::Parameter for KappaStats {}
+
+/// MIP kappa computation.
+/// <https://www.ibm.com/docs/en/icos/22.1.1?topic=parameters-mip-kappa-computation>
+#[derive(Copy, Clone, Debug)]
+pub enum KappaStats {
+    Off,
+    Auto,
+    Sample,
+    Full,
+}
+
+impl Parameter for KappaStats {
+    fn value(&self) -> ParameterValue {
+        ParameterValue::Integer(match self {
+            Self::Off => CPX_MIPKAPPA_OFF,
+            Self::Auto => CPX_MIPKAPPA_AUTO as i32,
+            Self::Sample => CPX_MIPKAPPA_SAMPLE as i32,
+            Self::Full => CPX_MIPKAPPA_FULL as i32,
+        })
+    }
+
+    fn id(&self) -> u32 {
+        CPXPARAM_MIP_Strategy_KappaStats
+    }
+}
+
+impl private::Parameter for HeuristicFreq {}
+
+/// MIP heuristic frequency.
+/// <https://www.ibm.com/docs/en/icos/22.1.1?topic=parameters-mip-heuristic-frequency>
+#[derive(Copy, Clone, Debug)]
+pub enum HeuristicFreq {
+    /// Do not apply the periodic heuristic
+    None,
+    Automatic,
+    /// Apply the periodic heuristic every given number of nodes
+    EveryNodes(u32),
+}
+
+impl Parameter for HeuristicFreq {
+    fn value(&self) -> ParameterValue {
+        ParameterValue::Long(match self {
+            Self::None => -1,
+            Self::Automatic => 0,
+            Self::EveryNodes(nodes) => *nodes as i64,
+        })
+    }
+
+    fn id(&self) -> u32 {
+        CPXPARAM_MIP_Strategy_HeuristicFreq
+    }
+}
@@ -1,8 +1,12 @@
 pub mod barrier;
+pub mod benders;
 pub mod emphasis;
+pub mod lp_method;
 pub mod mip;
+pub mod network;
 pub mod preprocessing;
 pub mod read;
+pub mod simplex;
 pub mod tolerances;
 
 use std::{
@@ -11,13 +15,16 @@ use std::{
 };
 
 use ffi::{
-    CPXPARAM_Advance, CPXPARAM_Parallel, CPXPARAM_RandomSeed, CPXPARAM_ScreenOutput,
-    CPXPARAM_Threads, CPXPARAM_TimeLimit,
+    CPXPARAM_Advance, CPXPARAM_DetTimeLimit, CPXPARAM_Parallel, CPXPARAM_RandomSeed,
+    CPXPARAM_ScreenOutput, CPXPARAM_Threads, CPXPARAM_TimeLimit, CPXPARAM_WorkDir,
+    CPXPARAM_WorkMem,
 };
 
+use crate::errors::{self, Result};
+
 // TODO: Not all parameters have been implemented yet.
 // When implementing a parameter, make sure that the rust namespace matches the CPLEX namespace.
-// Next parameter to implement: https://www.ibm.com/docs/en/icos/12.9.0?topic=parameters-benders-strategy
+// Next parameter to implement: https://www.ibm.com/docs/en/icos/12.9.0?topic=parameters-bound-strengthening-switch
 
 pub(crate) mod private {
     pub trait Parameter {}
@@ -29,6 +36,9 @@ impl private::Parameter for Threads {}
 impl private::Parameter for ScreenOutput {}
 impl private::Parameter for RandomSeed {}
 impl private::Parameter for TimeLimit {}
+impl private::Parameter for DetTimeLimit {}
+impl private::Parameter for WorkDir {}
+impl private::Parameter for WorkMem {}
 
 /// Parameter trait. It is a sealed trait, as it is supposed to be implemented
 /// only within the cples_rs library
@@ -45,6 +55,34 @@ pub enum ParameterValue {
     String(&'static str),
 }
 
+/// A memory size, as expected by the CPLEX parameters expressed in megabytes.
+#[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
+pub struct MemorySize(f64);
+
+impl MemorySize {
+    pub fn from_bytes(bytes: u64) -> Self {
+        Self(bytes as f64 / (1024.0 * 1024.0))
+    }
+
+    pub fn from_megabytes(megabytes: f64) -> Result<Self> {
+        if !megabytes.is_finite() || megabytes < 0.0 {
+            return Err(errors::Input::from_message(
+                "Memory size cannot be < 0.0 or non-finite".to_string(),
+            )
+            .into());
+        }
+        Ok(Self(megabytes))
+    }
+
+    pub fn from_gigabytes(gigabytes: f64) -> Result<Self> {
+        Self::from_megabytes(gigabytes * 1024.0)
+    }
+
+    pub fn as_megabytes(&self) -> f64 {
+        self.0
+    }
+}
+
 /// Advanced start switch.
 /// <https://www.ibm.com/docs/en/icos/22.1.1?topic=parameters-advanced-start-switch>
 #[derive(Copy, Clone, Debug)]
@@ -150,3 +188,60 @@ impl Parameter for TimeLimit {
         CPXPARAM_TimeLimit
     }
 }
+
+/// Deterministic time limit, expressed in ticks.
+/// <https://www.ibm.com/docs/en/icos/22.1.1?topic=parameters-deterministic-time-limit>
+#[derive(Copy, Clone, Debug)]
+pub struct DetTimeLimit(f64);
+
+impl DetTimeLimit {
+    pub fn new(ticks: f64) -> Result<Self> {
+        if ticks.is_nan() || ticks < 0.0 {
+            return Err(errors::Input::from_message(
+                "CPXPARAM_DetTimeLimit cannot be < 0.0 or NaN".to_string(),
+            )
+            .into());
+        }
+        Ok(Self(ticks))
+    }
+}
+
+impl Parameter for DetTimeLimit {
+    fn value(&self) -> ParameterValue {
+        ParameterValue::Double(self.0)
+    }
+
+    fn id(&self) -> u32 {
+        CPXPARAM_DetTimeLimit
+    }
+}
+
+/// Directory for working files.
+/// <https://www.ibm.com/docs/en/icos/22.1.1?topic=parameters-directory-working-files>
+#[derive(Copy, Clone, Debug)]
+pub struct WorkDir(pub &'static str);
+
+impl Parameter for WorkDir {
+    fn value(&self) -> ParameterValue {
+        ParameterValue::String(self.0)
+    }
+
+    fn id(&self) -> u32 {
+        CPXPARAM_WorkDir
+    }
+}
+
+/// Memory available for working storage.
+/// <https://www.ibm.com/docs/en/icos/22.1.1?topic=parameters-memory-available-working-storage>
+#[derive(Copy, Clone, Debug)]
+pub struct WorkMem(pub MemorySize);
+
+impl Parameter for WorkMem {
+    fn value(&self) -> ParameterValue {
+        ParameterValue::Double(self.0.as_megabytes())
+    }
+
+    fn id(&self) -> u32 {
+        CPXPARAM_WorkMem
+    }
+}
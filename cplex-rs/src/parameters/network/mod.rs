@@ -0,0 +1,99 @@
+pub mod tolerances;
+
+use ffi::{
+    CPXPARAM_Network_Display, CPXPARAM_Network_Iterations, CPXPARAM_Network_NetFind,
+    CPXPARAM_Network_Pricing,
+};
+
+use crate::parameters::{private, Parameter, ParameterValue};
+
+impl private::Parameter for Display {}
+impl private::Parameter for Iterations {}
+impl private::Parameter for NetFind {}
+impl private::Parameter for Pricing {}
+
+/// Network logging display switch.
+/// <https://www.ibm.com/docs/en/icos/22.1.1?topic=parameters-network-logging-display-switch>
+#[derive(Copy, Clone, Debug)]
+pub enum Display {
+    None,
+    TrueObjective,
+    PenalizedObjective,
+}
+
+impl Parameter for Display {
+    fn value(&self) -> ParameterValue {
+        ParameterValue::Integer(match self {
+            Self::None => 0,
+            Self::TrueObjective => 1,
+            Self::PenalizedObjective => 2,
+        })
+    }
+
+    fn id(&self) -> u32 {
+        CPXPARAM_Network_Display
+    }
+}
+
+/// Network simplex iteration limit.
+/// <https://www.ibm.com/docs/en/icos/22.1.1?topic=parameters-network-simplex-iteration-limit>
+#[derive(Copy, Clone, Debug)]
+pub struct Iterations(pub u64);
+
+impl Parameter for Iterations {
+    fn value(&self) -> ParameterValue {
+        ParameterValue::Long(self.0 as i64)
+    }
+
+    fn id(&self) -> u32 {
+        CPXPARAM_Network_Iterations
+    }
+}
+
+/// Simplex network extraction level.
+/// <https://www.ibm.com/docs/en/icos/22.1.1?topic=parameters-simplex-network-extraction-level>
+#[derive(Copy, Clone, Debug)]
+pub enum NetFind {
+    Pure,
+    Reflect,
+    Scale,
+}
+
+impl Parameter for NetFind {
+    fn value(&self) -> ParameterValue {
+        ParameterValue::Integer(match self {
+            Self::Pure => 1,
+            Self::Reflect => 2,
+            Self::Scale => 3,
+        })
+    }
+
+    fn id(&self) -> u32 {
+        CPXPARAM_Network_NetFind
+    }
+}
+
+/// Network simplex pricing algorithm.
+/// <https://www.ibm.com/docs/en/icos/22.1.1?topic=parameters-network-simplex-pricing-algorithm>
+#[derive(Copy, Clone, Debug)]
+pub enum Pricing {
+    Automatic,
+    Partial,
+    MultiplePartial,
+    MultiplePartialWithSorting,
+}
+
+impl Parameter for Pricing {
+    fn value(&self) -> ParameterValue {
+        ParameterValue::Integer(match self {
+            Self::Automatic => 0,
+            Self::Partial => 1,
+            Self::MultiplePartial => 2,
+            Self::MultiplePartialWithSorting => 3,
+        })
+    }
+
+    fn id(&self) -> u32 {
+        CPXPARAM_Network_Pricing
+    }
+}
@@ -0,0 +1,61 @@
+use ffi::{CPXPARAM_Network_Tolerances_Feasibility, CPXPARAM_Network_Tolerances_Optimality};
+
+use crate::errors::{self, Result};
+use crate::parameters::{private, Parameter, ParameterValue};
+
+impl private::Parameter for Feasibility {}
+impl private::Parameter for Optimality {}
+
+/// Feasibility tolerance for the network simplex optimizer.
+/// <https://www.ibm.com/docs/en/icos/22.1.1?topic=parameters-feasibility-tolerance-network-primal-optimizer>
+#[derive(Copy, Clone, Debug)]
+pub struct Feasibility(f64);
+
+impl Feasibility {
+    pub fn new(value: f64) -> Result<Self> {
+        if !(1e-11..=1e-1).contains(&value) {
+            return Err(errors::Input::from_message(
+                "CPXPARAM_Network_Tolerances_Feasibility cannot be < 1e-11 or > 1e-1".to_string(),
+            )
+            .into());
+        }
+        Ok(Self(value))
+    }
+}
+
+impl Parameter for Feasibility {
+    fn value(&self) -> ParameterValue {
+        ParameterValue::Double(self.0)
+    }
+
+    fn id(&self) -> u32 {
+        CPXPARAM_Network_Tolerances_Feasibility
+    }
+}
+
+/// Optimality tolerance for the network simplex optimizer.
+/// <https://www.ibm.com/docs/en/icos/22.1.1?topic=parameters-optimality-tolerance-network-optimizer>
+#[derive(Copy, Clone, Debug)]
+pub struct Optimality(f64);
+
+impl Optimality {
+    pub fn new(value: f64) -> Result<Self> {
+        if !(1e-11..=1e-1).contains(&value) {
+            return Err(errors::Input::from_message(
+                "CPXPARAM_Network_Tolerances_Optimality cannot be < 1e-11 or > 1e-1".to_string(),
+            )
+            .into());
+        }
+        Ok(Self(value))
+    }
+}
+
+impl Parameter for Optimality {
+    fn value(&self) -> ParameterValue {
+        ParameterValue::Double(self.0)
+    }
+
+    fn id(&self) -> u32 {
+        CPXPARAM_Network_Tolerances_Optimality
+    }
+}
@@ -1,9 +1,12 @@
-use ffi::{CPXPARAM_Preprocessing_Aggregator, CPXPARAM_Preprocessing_Fill};
+use ffi::{
+    CPXPARAM_Preprocessing_Aggregator, CPXPARAM_Preprocessing_Fill, CPXPARAM_Preprocessing_Presolve,
+};
 
 use crate::parameters::{private, Parameter, ParameterValue};
 
 impl private::Parameter for Fill {}
 impl private::Parameter for Aggregator {}
+impl private::Parameter for Presolve {}
 
 /// Preprocessing aggregator fill.
 /// <https://www.ibm.com/docs/en/icos/22.1.1?topic=parameters-preprocessing-aggregator-fill>
@@ -40,3 +43,18 @@ impl Parameter for Aggregator {
         CPXPARAM_Preprocessing_Aggregator
     }
 }
+
+/// Presolve switch.
+/// <https://www.ibm.com/docs/en/icos/22.1.1?topic=parameters-presolve-switch>
+#[derive(Copy, Clone, Debug)]
+pub struct Presolve(pub bool);
+
+impl Parameter for Presolve {
+    fn value(&self) -> ParameterValue {
+        ParameterValue::Integer(if self.0 { 1 } else { 0 })
+    }
+
+    fn id(&self) -> u32 {
+        CPXPARAM_Preprocessing_Presolve
+    }
+}
@@ -0,0 +1,94 @@
+use ffi::{
+    CPXPARAM_Simplex_Limits_Iterations, CPXPARAM_Simplex_Limits_LowerObj,
+    CPXPARAM_Simplex_Limits_Perturbation, CPXPARAM_Simplex_Limits_Singularity,
+    CPXPARAM_Simplex_Limits_UpperObj,
+};
+
+use crate::parameters::{private, Parameter, ParameterValue};
+
+impl private::Parameter for Iterations {}
+impl private::Parameter for LowerObj {}
+impl private::Parameter for Perturbation {}
+impl private::Parameter for Singularity {}
+impl private::Parameter for UpperObj {}
+
+/// Simplex maximum iteration limit.
+/// <https://www.ibm.com/docs/en/icos/22.1.1?topic=parameters-simplex-maximum-iteration-limit>
+#[derive(Copy, Clone, Debug)]
+pub struct Iterations(pub u64);
+
+impl Parameter for Iterations {
+    fn value(&self) -> ParameterValue {
+        ParameterValue::Long(self.0 as i64)
+    }
+
+    fn id(&self) -> u32 {
+        CPXPARAM_Simplex_Limits_Iterations
+    }
+}
+
+/// Lower objective value limit.
+/// <https://www.ibm.com/docs/en/icos/22.1.1?topic=parameters-lower-objective-value-limit>
+#[derive(Copy, Clone, Debug)]
+pub struct LowerObj(pub f64);
+
+impl Parameter for LowerObj {
+    fn value(&self) -> ParameterValue {
+        ParameterValue::Double(self.0)
+    }
+
+    fn id(&self) -> u32 {
+        CPXPARAM_Simplex_Limits_LowerObj
+    }
+}
+
+/// Simplex perturbation limit.
+/// <https://www.ibm.com/docs/en/icos/22.1.1?topic=parameters-simplex-perturbation-limit>
+#[derive(Copy, Clone, Debug)]
+pub enum Perturbation {
+    Automatic,
+    DegenerateIterations(u32),
+}
+
+impl Parameter for Perturbation {
+    fn value(&self) -> ParameterValue {
+        ParameterValue::Integer(match self {
+            Self::Automatic => 0,
+            &Self::DegenerateIterations(n) => n as i32,
+        })
+    }
+
+    fn id(&self) -> u32 {
+        CPXPARAM_Simplex_Limits_Perturbation
+    }
+}
+
+/// Simplex singularity repair limit.
+/// <https://www.ibm.com/docs/en/icos/22.1.1?topic=parameters-simplex-singularity-repair-limit>
+#[derive(Copy, Clone, Debug)]
+pub struct Singularity(pub u32);
+
+impl Parameter for Singularity {
+    fn value(&self) -> ParameterValue {
+        ParameterValue::Integer(self.0 as i32)
+    }
+
+    fn id(&self) -> u32 {
+        CPXPARAM_Simplex_Limits_Singularity
+    }
+}
+
+/// Upper objective value limit.
+/// <https://www.ibm.com/docs/en/icos/22.1.1?topic=parameters-upper-objective-value-limit>
+#[derive(Copy, Clone, Debug)]
+pub struct UpperObj(pub f64);
+
+impl Parameter for UpperObj {
+    fn value(&self) -> ParameterValue {
+        ParameterValue::Double(self.0)
+    }
+
+    fn id(&self) -> u32 {
+        CPXPARAM_Simplex_Limits_UpperObj
+    }
+}
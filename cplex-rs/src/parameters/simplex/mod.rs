@@ -0,0 +1,163 @@
+pub mod limits;
+pub mod perturbation;
+pub mod tolerances;
+
+use ffi::{
+    CPXPARAM_Simplex_Crash, CPXPARAM_Simplex_DGradient, CPXPARAM_Simplex_Display,
+    CPXPARAM_Simplex_PGradient, CPXPARAM_Simplex_Pricing, CPXPARAM_Simplex_Refactor,
+};
+
+use crate::parameters::{private, Parameter, ParameterValue};
+
+impl private::Parameter for Crash {}
+impl private::Parameter for DGradient {}
+impl private::Parameter for Display {}
+impl private::Parameter for PGradient {}
+impl private::Parameter for Pricing {}
+impl private::Parameter for Refactor {}
+
+/// Simplex crash ordering.
+/// <https://www.ibm.com/docs/en/icos/22.1.1?topic=parameters-simplex-crash-ordering>
+#[derive(Copy, Clone, Debug)]
+pub enum Crash {
+    Alternative,
+    NoCrash,
+    Default,
+}
+
+impl Parameter for Crash {
+    fn value(&self) -> ParameterValue {
+        ParameterValue::Integer(match self {
+            Self::Alternative => -1,
+            Self::NoCrash => 0,
+            Self::Default => 1,
+        })
+    }
+
+    fn id(&self) -> u32 {
+        CPXPARAM_Simplex_Crash
+    }
+}
+
+/// Dual simplex pricing algorithm.
+/// <https://www.ibm.com/docs/en/icos/22.1.1?topic=parameters-dual-simplex-pricing-algorithm>
+#[derive(Copy, Clone, Debug)]
+pub enum DGradient {
+    Automatic,
+    Standard,
+    SteepestEdge,
+    SteepestEdgeInSlackSpace,
+    SteepestEdgeUnitInitialNorms,
+    Devex,
+}
+
+impl Parameter for DGradient {
+    fn value(&self) -> ParameterValue {
+        ParameterValue::Integer(match self {
+            Self::Automatic => 0,
+            Self::Standard => 1,
+            Self::SteepestEdge => 2,
+            Self::SteepestEdgeInSlackSpace => 3,
+            Self::SteepestEdgeUnitInitialNorms => 4,
+            Self::Devex => 5,
+        })
+    }
+
+    fn id(&self) -> u32 {
+        CPXPARAM_Simplex_DGradient
+    }
+}
+
+/// Simplex iteration information display.
+/// <https://www.ibm.com/docs/en/icos/22.1.1?topic=parameters-simplex-iteration-information-display>
+#[derive(Copy, Clone, Debug)]
+pub enum Display {
+    None,
+    AfterRefactorization,
+    EachIteration,
+}
+
+impl Parameter for Display {
+    fn value(&self) -> ParameterValue {
+        ParameterValue::Integer(match self {
+            Self::None => 0,
+            Self::AfterRefactorization => 1,
+            Self::EachIteration => 2,
+        })
+    }
+
+    fn id(&self) -> u32 {
+        CPXPARAM_Simplex_Display
+    }
+}
+
+/// Primal simplex pricing algorithm.
+/// <https://www.ibm.com/docs/en/icos/22.1.1?topic=parameters-primal-simplex-pricing-algorithm>
+#[derive(Copy, Clone, Debug)]
+pub enum PGradient {
+    ReducedCost,
+    Automatic,
+    Devex,
+    SteepestEdge,
+    SteepestEdgeSlackInitialNorms,
+    Full,
+}
+
+impl Parameter for PGradient {
+    fn value(&self) -> ParameterValue {
+        ParameterValue::Integer(match self {
+            Self::ReducedCost => -1,
+            Self::Automatic => 0,
+            Self::Devex => 1,
+            Self::SteepestEdge => 2,
+            Self::SteepestEdgeSlackInitialNorms => 3,
+            Self::Full => 4,
+        })
+    }
+
+    fn id(&self) -> u32 {
+        CPXPARAM_Simplex_PGradient
+    }
+}
+
+/// Pricing candidate list size.
+/// <https://www.ibm.com/docs/en/icos/22.1.1?topic=parameters-pricing-candidate-list>
+#[derive(Copy, Clone, Debug)]
+pub enum Pricing {
+    Automatic,
+    Candidates(u32),
+}
+
+impl Parameter for Pricing {
+    fn value(&self) -> ParameterValue {
+        ParameterValue::Integer(match self {
+            Self::Automatic => 0,
+            &Self::Candidates(n) => n as i32,
+        })
+    }
+
+    fn id(&self) -> u32 {
+        CPXPARAM_Simplex_Pricing
+    }
+}
+
+/// Simplex refactoring frequency.
+/// <https://www.ibm.com/docs/en/icos/22.1.1?topic=parameters-simplex-refactoring-frequency>
+#[derive(Copy, Clone, Debug)]
+pub enum Refactor {
+    Automatic,
+    Iterations(u32),
+}
+
+impl Parameter for Refactor {
+    fn value(&self) -> ParameterValue {
+        ParameterValue::Integer(match self {
+            Self::Automatic => 0,
+            &Self::Iterations(n) => n as i32,
+        })
+    }
+
+    fn id(&self) -> u32 {
+        CPXPARAM_Simplex_Refactor
+    }
+}
@@ -0,0 +1,49 @@
+use ffi::{CPXPARAM_Simplex_Perturbation_Constant, CPXPARAM_Simplex_Perturbation_Indicator};
+
+use crate::errors::{self, Result};
+use crate::parameters::{private, Parameter, ParameterValue};
+
+impl private::Parameter for Constant {}
+impl private::Parameter for Indicator {}
+
+/// Perturbation constant.
+/// <https://www.ibm.com/docs/en/icos/22.1.1?topic=parameters-perturbation-constant>
+#[derive(Copy, Clone, Debug)]
+pub struct Constant(f64);
+
+impl Constant {
+    pub fn new(value: f64) -> Result<Self> {
+        if !(1e-8..).contains(&value) {
+            return Err(errors::Input::from_message(
+                "CPXPARAM_Simplex_Perturbation_Constant cannot be < 1e-8 or NaN".to_string(),
+            )
+            .into());
+        }
+        Ok(Self(value))
+    }
+}
+
+impl Parameter for Constant {
+    fn value(&self) -> ParameterValue {
+        ParameterValue::Double(self.0)
+    }
+
+    fn id(&self) -> u32 {
+        CPXPARAM_Simplex_Perturbation_Constant
+    }
+}
+
+/// Simplex perturbation switch.
+/// <https://www.ibm.com/docs/en/icos/22.1.1?topic=parameters-simplex-perturbation-switch>
+#[derive(Copy, Clone, Debug)]
+pub struct Indicator(pub bool);
+
+impl Parameter for Indicator {
+    fn value(&self) -> ParameterValue {
+        ParameterValue::Integer(if self.0 { 1 } else { 0 })
+    }
+
+    fn id(&self) -> u32 {
+        CPXPARAM_Simplex_Perturbation_Indicator
+    }
+}
@@ -0,0 +1,92 @@
+use ffi::{
+    CPXPARAM_Simplex_Tolerances_Feasibility, CPXPARAM_Simplex_Tolerances_Markowitz,
+    CPXPARAM_Simplex_Tolerances_Optimality,
+};
+
+use crate::errors::{self, Result};
+use crate::parameters::{private, Parameter, ParameterValue};
+
+impl private::Parameter for Feasibility {}
+impl private::Parameter for Markowitz {}
+impl private::Parameter for Optimality {}
+
+/// Feasibility tolerance.
+/// <https://www.ibm.com/docs/en/icos/22.1.1?topic=parameters-feasibility-tolerance>
+#[derive(Copy, Clone, Debug)]
+pub struct Feasibility(f64);
+
+impl Feasibility {
+    pub fn new(value: f64) -> Result<Self> {
+        if !(1e-9..=1e-1).contains(&value) {
+            return Err(errors::Input::from_message(
+                "CPXPARAM_Simplex_Tolerances_Feasibility cannot be < 1e-9 or > 1e-1".to_string(),
+            )
+            .into());
+        }
+        Ok(Self(value))
+    }
+}
+
+impl Parameter for Feasibility {
+    fn value(&self) -> ParameterValue {
+        ParameterValue::Double(self.0)
+    }
+
+    fn id(&self) -> u32 {
+        CPXPARAM_Simplex_Tolerances_Feasibility
+    }
+}
+
+/// Markowitz tolerance.
+/// <https://www.ibm.com/docs/en/icos/22.1.1?topic=parameters-markowitz-tolerance>
+#[derive(Copy, Clone, Debug)]
+pub struct Markowitz(f64);
+
+impl Markowitz {
+    pub fn new(value: f64) -> Result<Self> {
+        if !(0.0001..=0.99999).contains(&value) {
+            return Err(errors::Input::from_message(
+                "CPXPARAM_Simplex_Tolerances_Markowitz cannot be < 0.0001 or > 0.99999".to_string(),
+            )
+            .into());
+        }
+        Ok(Self(value))
+    }
+}
+
+impl Parameter for Markowitz {
+    fn value(&self) -> ParameterValue {
+        ParameterValue::Double(self.0)
+    }
+
+    fn id(&self) -> u32 {
+        CPXPARAM_Simplex_Tolerances_Markowitz
+    }
+}
+
+/// Optimality tolerance.
+/// <https://www.ibm.com/docs/en/icos/22.1.1?topic=parameters-optimality-tolerance>
+#[derive(Copy, Clone, Debug)]
+pub struct Optimality(f64);
+
+impl Optimality {
+    pub fn new(value: f64) -> Result<Self> {
+        if !(1e-9..=1e-1).contains(&value) {
+            return Err(errors::Input::from_message(
+                "CPXPARAM_Simplex_Tolerances_Optimality cannot be < 1e-9 or > 1e-1".to_string(),
+            )
+            .into());
+        }
+        Ok(Self(value))
+    }
+}
+
+impl Parameter for Optimality {
+    fn value(&self) -> ParameterValue {
+        ParameterValue::Double(self.0)
+    }
+
+    fn id(&self) -> u32 {
+        CPXPARAM_Simplex_Tolerances_Optimality
+    }
+}
@@ -0,0 +1,121 @@
+use std::{
+    ffi::{c_int, c_void},
+    panic::{catch_unwind, AssertUnwindSafe},
+    sync::{Mutex, PoisonError},
+    time::{Duration, Instant},
+};
+
+use ffi::{
+    cpxcallbackcontext, CPXcallbackgetinfodbl, CPXcallbackgetinfoint, CPXcallbackgetinfolong,
+    CPXCALLBACKINFO_CPXCALLBACKINFO_BEST_BND, CPXCALLBACKINFO_CPXCALLBACKINFO_BEST_SOL,
+    CPXCALLBACKINFO_CPXCALLBACKINFO_DETTIME, CPXCALLBACKINFO_CPXCALLBACKINFO_FEASIBLE,
+    CPXCALLBACKINFO_CPXCALLBACKINFO_ITCOUNT, CPXCALLBACKINFO_CPXCALLBACKINFO_NODECOUNT,
+    CPXCALLBACKINFO_CPXCALLBACKINFO_NODESLEFT, CPXERR_CALLBACK, CPXLONG,
+};
+
+pub(crate) type ProgressClosure = Box<dyn FnMut(MipProgress) + Send>;
+
+/// A snapshot of the progress of a MIP optimization
+#[derive(Clone, Copy, Debug)]
+pub struct MipProgress {
+    /// Wall time elapsed since the beginning of the optimization
+    pub elapsed: Duration,
+    /// Deterministic time elapsed since the beginning of the optimization, in ticks
+    pub deterministic_time: f64,
+    pub nodes: i64,
+    pub nodes_left: i64,
+    pub iterations: i64,
+    pub best_bound: f64,
+    /// Objective value of the best integer solution found so far, if any
+    pub incumbent: Option<f64>,
+    /// Relative MIP gap between the incumbent and the best bound, if an incumbent exists
+    pub gap: Option<f64>,
+}
+
+pub(crate) struct ProgressHandler {
+    inner: Mutex<(Instant, ProgressClosure)>,
+}
+
+impl ProgressHandler {
+    pub(crate) fn new<F: FnMut(MipProgress) + Send + 'static>(handler: F) -> Self {
+        Self {
+            inner: Mutex::new((Instant::now(), Box::new(handler))),
+        }
+    }
+
+    pub(crate) fn reset_start(&self) {
+        self.inner.lock().unwrap_or_else(PoisonError::into_inner).0 = Instant::now();
+    }
+}
+
+pub(crate) unsafe extern "C" fn progress_callback(
+    context: *mut cpxcallbackcontext,
+    _context_id: CPXLONG,
+    user_handle: *mut c_void,
+) -> c_int {
+    let handler = &*(user_handle as *const ProgressHandler);
+
+    // A panic must not unwind into CPLEX: report it as a callback failure instead
+    catch_unwind(AssertUnwindSafe(|| report_progress(context, handler)))
+        .unwrap_or(CPXERR_CALLBACK as c_int)
+}
+
+unsafe fn report_progress(context: *mut cpxcallbackcontext, handler: &ProgressHandler) -> c_int {
+    let mut nodes = 0;
+    let mut nodes_left = 0;
+    let mut iterations = 0;
+    let mut feasible = 0;
+    let mut best_bound = 0.0;
+    let mut best_solution = 0.0;
+    let mut deterministic_time = 0.0;
+    let status = CPXcallbackgetinfolong(
+        context,
+        CPXCALLBACKINFO_CPXCALLBACKINFO_NODECOUNT,
+        &mut nodes,
+    ) | CPXcallbackgetinfolong(
+        context,
+        CPXCALLBACKINFO_CPXCALLBACKINFO_NODESLEFT,
+        &mut nodes_left,
+    ) | CPXcallbackgetinfolong(
+        context,
+        CPXCALLBACKINFO_CPXCALLBACKINFO_ITCOUNT,
+        &mut iterations,
+    ) | CPXcallbackgetinfoint(
+        context,
+        CPXCALLBACKINFO_CPXCALLBACKINFO_FEASIBLE,
+        &mut feasible,
+    ) | CPXcallbackgetinfodbl(
+        context,
+        CPXCALLBACKINFO_CPXCALLBACKINFO_BEST_BND,
+        &mut best_bound,
+    ) | CPXcallbackgetinfodbl(
+        context,
+        CPXCALLBACKINFO_CPXCALLBACKINFO_BEST_SOL,
+        &mut best_solution,
+    ) | CPXcallbackgetinfodbl(
+        context,
+        CPXCALLBACKINFO_CPXCALLBACKINFO_DETTIME,
+        &mut deterministic_time,
+    );
+    if status != 0 {
+        return status;
+    }
+
+    let incumbent = (feasible != 0).then_some(best_solution);
+    let gap = incumbent.map(|incumbent| (incumbent - best_bound).abs() / (1e-10 + incumbent.abs()));
+
+    let mut inner = handler.inner.lock().unwrap_or_else(PoisonError::into_inner);
+    let progress = MipProgress {
+        elapsed: inner.0.elapsed(),
+        deterministic_time,
+        nodes,
+        nodes_left,
+        iterations,
+        best_bound,
+        incumbent,
+        gap,
+    };
+    (inner.1)(progress);
+
+    0
+}
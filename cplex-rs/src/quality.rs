@@ -0,0 +1,32 @@
+/// Measures of the numerical quality of the current solution of a problem.
+/// <https://www.ibm.com/docs/en/icos/22.1.1?topic=g-cpxxgetdblquality-cpxgetdblquality>
+///
+/// Measures which are not available for the current solution (e.g. dual infeasibilities of
+/// a MIP solution, or the condition number of a problem solved without a basis) are `None`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SolutionQuality {
+    pub max_primal_infeasibility: f64,
+    pub max_scaled_primal_infeasibility: Option<f64>,
+    pub max_dual_infeasibility: Option<f64>,
+    pub max_scaled_dual_infeasibility: Option<f64>,
+    pub max_integer_infeasibility: Option<f64>,
+    /// Condition number of the optimal basis
+    pub kappa: Option<f64>,
+    /// Statistics of the condition numbers of the bases seen during a MIP solve, only
+    /// available when `parameters::mip::strategy::KappaStats` is enabled
+    pub mip_kappa: Option<MipKappaStats>,
+}
+
+/// Statistics of the condition numbers of the LP relaxations solved during a MIP solve.
+/// <https://www.ibm.com/docs/en/icos/22.1.1?topic=mip-measuring-conditioning-kappa-statistics>
+///
+/// Percentages are in the range `[0, 1]`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct MipKappaStats {
+    pub stable: f64,
+    pub suspicious: f64,
+    pub unstable: f64,
+    pub ill_posed: f64,
+    pub max_kappa: f64,
+    pub attention: f64,
+}
@@ -0,0 +1,20 @@
+/// The range over which a coefficient can vary without changing the optimal basis.
+/// <https://www.ibm.com/docs/en/icos/22.1.1?topic=optimizer-sensitivity-analysis>
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SensitivityRange {
+    pub lower: f64,
+    pub upper: f64,
+}
+
+impl SensitivityRange {
+    pub fn contains(&self, value: f64) -> bool {
+        self.lower <= value && value <= self.upper
+    }
+}
+
+/// The ranges over which the bounds of a variable can vary without changing the optimal basis.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct BoundRanges {
+    pub lower_bound: SensitivityRange,
+    pub upper_bound: SensitivityRange,
+}
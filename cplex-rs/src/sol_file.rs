@@ -0,0 +1,340 @@
+//! Pure rust reader for the CPLEX solution (`.sol`) and MIP start (`.mst`) XML formats.
+//!
+//! This module does not call CPLEX and is available without the `cplex` feature, so it can
+//! be used to read the results written by `Problem::write_solution` or
+//! `Problem::write_mip_starts` from services which do not link against CPLEX.
+
+use std::{collections::HashMap, path::Path};
+
+use crate::errors::{self, Result};
+
+/// A solution stored in a CPLEX solution or MIP start file
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SolutionRecord {
+    pub problem_name: Option<String>,
+    pub solution_name: Option<String>,
+    pub objective_value: Option<f64>,
+    pub status: Option<i32>,
+    pub status_string: Option<String>,
+    pub variables: Vec<VariableRecord>,
+    pub constraints: Vec<ConstraintRecord>,
+}
+
+/// The value of a variable in a `SolutionRecord`
+#[derive(Clone, Debug, PartialEq)]
+pub struct VariableRecord {
+    pub name: String,
+    pub index: Option<usize>,
+    pub value: f64,
+    pub reduced_cost: Option<f64>,
+}
+
+/// The slack and dual value of a linear constraint in a `SolutionRecord`
+#[derive(Clone, Debug, PartialEq)]
+pub struct ConstraintRecord {
+    pub name: String,
+    pub index: Option<usize>,
+    pub slack: Option<f64>,
+    pub dual: Option<f64>,
+}
+
+/// Read all the solutions stored in a CPLEX solution or MIP start file.
+pub fn read<P: AsRef<Path>>(path: P) -> Result<Vec<SolutionRecord>> {
+    let content = std::fs::read_to_string(path)?;
+    parse(&content)
+}
+
+/// Parse all the solutions stored in the content of a CPLEX solution or MIP start file.
+///
+/// A solution file holds a single `CPLEXSolution` element, while a MIP start file holds
+/// several of them inside a `CPLEXSolutions` element.
+pub fn parse(content: &str) -> Result<Vec<SolutionRecord>> {
+    let mut solutions = vec![];
+    let mut current: Option<SolutionRecord> = None;
+
+    for tag in tags(content) {
+        let tag = tag?;
+        match (tag.name, tag.closing) {
+            ("CPLEXSolution", false) => {
+                if current.replace(SolutionRecord::default()).is_some() {
+                    return Err(parse_error("nested CPLEXSolution element"));
+                }
+                if tag.self_closing {
+                    solutions.extend(current.take());
+                }
+            }
+            ("CPLEXSolution", true) => {
+                let solution = current
+                    .take()
+                    .ok_or_else(|| parse_error("unexpected closing CPLEXSolution element"))?;
+                solutions.push(solution);
+            }
+            ("header", false) => {
+                let solution = current_solution(&mut current, "header")?;
+                solution.problem_name = tag.attribute("problemName").map(str::to_owned);
+                solution.solution_name = tag.attribute("solutionName").map(str::to_owned);
+                solution.objective_value = tag.parse_attribute("objectiveValue")?;
+                solution.status = tag.parse_attribute("solutionStatusValue")?;
+                solution.status_string = tag.attribute("solutionStatusString").map(str::to_owned);
+            }
+            ("variable", false) => {
+                let variable = VariableRecord {
+                    name: tag.required_attribute("name")?.to_owned(),
+                    index: tag.parse_attribute("index")?,
+                    value: tag
+                        .parse_attribute("value")?
+                        .ok_or_else(|| parse_error("variable without value"))?,
+                    reduced_cost: tag.parse_attribute("reducedCost")?,
+                };
+                current_solution(&mut current, "variable")?
+                    .variables
+                    .push(variable);
+            }
+            ("constraint", false) => {
+                let constraint = ConstraintRecord {
+                    name: tag.required_attribute("name")?.to_owned(),
+                    index: tag.parse_attribute("index")?,
+                    slack: tag.parse_attribute("slack")?,
+                    dual: tag.parse_attribute("dual")?,
+                };
+                current_solution(&mut current, "constraint")?
+                    .constraints
+                    .push(constraint);
+            }
+            _ => {}
+        }
+    }
+
+    if current.is_some() {
+        return Err(parse_error("unterminated CPLEXSolution element"));
+    }
+
+    Ok(solutions)
+}
+
+fn current_solution<'a>(
+    current: &'a mut Option<SolutionRecord>,
+    element: &str,
+) -> Result<&'a mut SolutionRecord> {
+    current
+        .as_mut()
+        .ok_or_else(|| parse_error(&format!("{element} element outside of CPLEXSolution")))
+}
+
+fn parse_error(message: &str) -> errors::Error {
+    errors::Input::from_message(format!("Invalid solution file: {message}")).into()
+}
+
+struct Tag<'a> {
+    name: &'a str,
+    closing: bool,
+    self_closing: bool,
+    attributes: HashMap<&'a str, String>,
+}
+
+impl<'a> Tag<'a> {
+    fn attribute(&self, name: &str) -> Option<&str> {
+        self.attributes.get(name).map(String::as_str)
+    }
+
+    fn required_attribute(&self, name: &str) -> Result<&str> {
+        self.attribute(name).ok_or_else(|| {
+            parse_error(&format!(
+                "missing attribute {name} in {} element",
+                self.name
+            ))
+        })
+    }
+
+    fn parse_attribute<T: std::str::FromStr>(&self, name: &str) -> Result<Option<T>> {
+        self.attribute(name)
+            .map(|value| {
+                value.trim().parse().map_err(|_| {
+                    parse_error(&format!("invalid value '{value}' for attribute {name}"))
+                })
+            })
+            .transpose()
+    }
+}
+
+/// Iterate over the element tags of an XML document, skipping declarations and comments.
+fn tags(content: &str) -> impl Iterator<Item = Result<Tag<'_>>> {
+    let mut rest = content;
+    std::iter::from_fn(move || loop {
+        let start = rest.find('<')?;
+        rest = &rest[start..];
+
+        if let Some(comment) = rest.strip_prefix("<!--") {
+            rest = comment
+                .find("-->")
+                .map(|end| &comment[end + 3..])
+                .unwrap_or("");
+            continue;
+        }
+
+        let Some(end) = rest.find('>') else {
+            rest = "";
+            return Some(Err(parse_error("unterminated tag")));
+        };
+        let body = &rest[1..end];
+        rest = &rest[end + 1..];
+
+        if body.starts_with('?') || body.starts_with('!') {
+            continue;
+        }
+
+        return Some(parse_tag(body));
+    })
+}
+
+fn parse_tag(body: &str) -> Result<Tag<'_>> {
+    let (closing, body) = match body.strip_prefix('/') {
+        Some(body) => (true, body),
+        None => (false, body),
+    };
+    let (self_closing, body) = match body.strip_suffix('/') {
+        Some(body) => (true, body),
+        None => (false, body),
+    };
+
+    let body = body.trim();
+    let name_end = body.find(|c: char| c.is_whitespace()).unwrap_or(body.len());
+    let name = &body[..name_end];
+    let mut rest = body[name_end..].trim_start();
+
+    let mut attributes = HashMap::new();
+    while !rest.is_empty() {
+        let eq = rest
+            .find('=')
+            .ok_or_else(|| parse_error(&format!("invalid attribute in {name} element")))?;
+        let key = rest[..eq].trim();
+        let value = rest[eq + 1..].trim_start();
+        let quote = value
+            .chars()
+            .next()
+            .filter(|&c| c == '"' || c == '\'')
+            .ok_or_else(|| parse_error(&format!("unquoted attribute {key} in {name} element")))?;
+        let value_end = value[1..].find(quote).ok_or_else(|| {
+            parse_error(&format!("unterminated attribute {key} in {name} element"))
+        })?;
+        attributes.insert(key, unescape(&value[1..value_end + 1]));
+        rest = value[value_end + 2..].trim_start();
+    }
+
+    Ok(Tag {
+        name,
+        closing,
+        self_closing,
+        attributes,
+    })
+}
+
+fn unescape(value: &str) -> String {
+    value
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const SOL: &str = r#"<?xml version = "1.0" encoding="UTF-8" standalone="yes"?>
+<CPLEXSolution version="1.2">
+ <header
+   problemName="mipex1"
+   solutionName="incumbent"
+   solutionIndex="-1"
+   objectiveValue="122.5"
+   solutionTypeValue="3"
+   solutionTypeString="primal"
+   solutionStatusValue="101"
+   solutionStatusString="integer optimal solution"
+   solutionMethodString="mip"
+   primalFeasible="1"
+   dualFeasible="1"
+   MIPNodes="0"
+   MIPIterations="3"
+   writeLevel="1"/>
+ <quality
+   epInt="1.0000000000000001e-05"
+   epRHS="9.9999999999999995e-07"
+   maxIntInfeas="0"
+   maxPrimalInfeas="0"
+   maxX="40"
+   maxSlack="2"/>
+ <linearConstraints>
+  <constraint name="c1" index="0" slack="0"/>
+  <constraint name="c&amp;2" index="1" slack="2"/>
+ </linearConstraints>
+ <variables>
+  <variable name="x0" index="0" value="40"/>
+  <variable name="x1" index="1" value="10.5"/>
+ </variables>
+</CPLEXSolution>
+"#;
+
+    const MST: &str = r#"<?xml version = "1.0" encoding="UTF-8" standalone="yes"?>
+<CPLEXSolutions version="1.2">
+ <CPLEXSolution version="1.2">
+  <header
+    problemName="mipex1"
+    solutionName="m1"
+    solutionIndex="0"
+    MIPStartEffortLevel="1"
+    writeLevel="2"/>
+  <variables>
+   <variable name="x0" index="0" value="1"/>
+  </variables>
+ </CPLEXSolution>
+ <!-- a comment <variable name="ignored" value="0"/> -->
+ <CPLEXSolution version="1.2">
+  <header
+    problemName="mipex1"
+    solutionName="m2"
+    solutionIndex="1"
+    MIPStartEffortLevel="4"
+    writeLevel="2"/>
+  <variables>
+   <variable name="x1" index="1" value="3"/>
+  </variables>
+ </CPLEXSolution>
+</CPLEXSolutions>
+"#;
+
+    #[test]
+    fn parse_solution_file() {
+        let solutions = parse(SOL).unwrap();
+        assert_eq!(solutions.len(), 1);
+
+        let solution = &solutions[0];
+        assert_eq!(solution.problem_name.as_deref(), Some("mipex1"));
+        assert_eq!(solution.objective_value, Some(122.5));
+        assert_eq!(solution.status, Some(101));
+        assert_eq!(solution.variables.len(), 2);
+        assert_eq!(solution.variables[1].name, "x1");
+        assert_eq!(solution.variables[1].index, Some(1));
+        assert_eq!(solution.variables[1].value, 10.5);
+        assert_eq!(solution.constraints[1].name, "c&2");
+        assert_eq!(solution.constraints[1].slack, Some(2.0));
+    }
+
+    #[test]
+    fn parse_mip_start_file() {
+        let solutions = parse(MST).unwrap();
+        assert_eq!(solutions.len(), 2);
+        assert_eq!(solutions[0].solution_name.as_deref(), Some("m1"));
+        assert_eq!(solutions[0].variables.len(), 1);
+        assert_eq!(solutions[1].solution_name.as_deref(), Some("m2"));
+        assert_eq!(solutions[1].variables[0].value, 3.0);
+    }
+
+    #[test]
+    fn parse_invalid_file() {
+        assert!(parse("<CPLEXSolution><variables>").is_err());
+        assert!(parse(r#"<variable name="x0" value="1"/>"#).is_err());
+    }
+}
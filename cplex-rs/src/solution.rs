@@ -1,19 +1,130 @@
-use crate::VariableId;
+use std::{io::Write, sync::OnceLock, time::Duration};
 
+use crate::{
+    errors::{self, Result},
+    logging::LogMessage,
+    names::NameIndex,
+    ModelIds, ObjectiveId, ProblemToken, VariableId,
+};
+
+/// Statistics about the optimization which produced a `Solution`
+#[derive(Clone, Copy, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SolveStats {
+    /// Best objective bound, only available for MIP problems
+    pub best_bound: Option<f64>,
+    /// Relative MIP gap, only available for MIP problems
+    pub mip_relative_gap: Option<f64>,
+    /// Number of processed branch-and-cut nodes, only available for MIP problems
+    pub nodes: Option<u64>,
+    pub simplex_iterations: u64,
+    pub barrier_iterations: u64,
+    pub wall_time: Duration,
+    /// Deterministic time spent in the optimization, in ticks
+    pub deterministic_time: f64,
+}
+
+/// The value of every variable of a solution paired with its name, which can be exchanged
+/// without the problem or model the solution comes from.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SolutionSnapshot {
+    pub objective_value: f64,
+    pub variables: Vec<(String, f64)>,
+}
+
+impl SolutionSnapshot {
+    /// Value of the first variable with the given name.
+    pub fn value(&self, name: &str) -> Option<f64> {
+        self.variables
+            .iter()
+            .find(|(n, _)| n == name)
+            .map(|&(_, value)| value)
+    }
+}
+
+/// A solution of a problem.
+///
+/// A deserialized solution is not bound to any problem: its values can be read by name or
+/// by position, or with the ids of a model once bound to it with `Model::bind_solution`.
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Solution {
+    #[cfg_attr(feature = "serde", serde(skip, default = "ProblemToken::new"))]
+    problem: ProblemToken,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    model: Option<ModelIds>,
     objective_value: f64,
     variable_values: Vec<f64>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    variable_names: Vec<String>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    constraint_names: Vec<String>,
+    /// Index of `variable_names`, built on the first lookup by name
+    #[cfg_attr(feature = "serde", serde(skip))]
+    name_index: OnceLock<NameIndex>,
+    objective_values: Vec<f64>,
+    solve_log: Option<Vec<LogMessage>>,
+    stats: SolveStats,
 }
 
 impl Solution {
-    pub(crate) fn new(variable_values: Vec<f64>, objective_value: f64) -> Self {
+    #[cfg(any(feature = "cplex", test))]
+    pub(crate) fn new(
+        problem: ProblemToken,
+        variable_values: Vec<f64>,
+        objective_value: f64,
+    ) -> Self {
         Self {
+            problem,
+            model: None,
             objective_value,
             variable_values,
+            variable_names: vec![],
+            constraint_names: vec![],
+            name_index: OnceLock::new(),
+            objective_values: vec![],
+            solve_log: None,
+            stats: SolveStats::default(),
         }
     }
 
+    #[cfg(any(feature = "cplex", test))]
+    pub(crate) fn with_names(
+        mut self,
+        variable_names: Vec<String>,
+        constraint_names: Vec<String>,
+    ) -> Self {
+        self.variable_names = variable_names;
+        self.constraint_names = constraint_names;
+        self.name_index = OnceLock::new();
+        self
+    }
+
+    #[cfg(any(feature = "cplex", test))]
+    pub(crate) fn with_model(mut self, model: Option<ModelIds>) -> Self {
+        self.model = model;
+        self
+    }
+
+    #[cfg(feature = "cplex")]
+    pub(crate) fn with_stats(mut self, stats: SolveStats) -> Self {
+        self.stats = stats;
+        self
+    }
+
+    #[cfg(feature = "cplex")]
+    pub(crate) fn with_solve_log(mut self, solve_log: Vec<LogMessage>) -> Self {
+        self.solve_log = Some(solve_log);
+        self
+    }
+
+    #[cfg(any(feature = "cplex", test))]
+    pub(crate) fn with_objective_values(mut self, objective_values: Vec<f64>) -> Self {
+        self.objective_values = objective_values;
+        self
+    }
+
     pub fn objective_value(&self) -> f64 {
         self.objective_value
     }
@@ -22,7 +133,268 @@ impl Solution {
         &self.variable_values
     }
 
+    /// Value of a variable in the solution.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the variable does not belong to the problem this solution comes from,
+    /// see `try_variable_value`.
     pub fn variable_value(&self, v: VariableId) -> f64 {
-        self.variable_values[v.0]
+        self.try_variable_value(v).unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Value of a variable in the solution, or an `errors::Input` error if the variable does
+    /// not belong to the problem this solution comes from.
+    pub fn try_variable_value(&self, v: VariableId) -> Result<f64> {
+        self.variable_values
+            .get(v.index)
+            .copied()
+            .filter(|_| self.problem == v.problem || self.model.is_some_and(|m| m.owns_variable(v)))
+            .ok_or_else(|| errors::Input::ForeignVariable { index: v.index }.into())
+    }
+
+    /// Names of the variables of the problem, in the order they have been added. Unnamed
+    /// variables are named `x<index + 1>`, as CPLEX does.
+    pub fn variable_names(&self) -> &[String] {
+        &self.variable_names
+    }
+
+    /// Names of the constraints of the problem, in the order they have been added. Unnamed
+    /// constraints are named `c<index + 1>`, as CPLEX does.
+    pub fn constraint_names(&self) -> &[String] {
+        &self.constraint_names
+    }
+
+    /// Value of the first variable with the given name.
+    pub fn value_by_name(&self, name: &str) -> Option<f64> {
+        let index = self.name_index.get_or_init(|| {
+            let mut index = NameIndex::default();
+            for (position, name) in self.variable_names.iter().enumerate() {
+                index.insert(name, position);
+            }
+            index
+        });
+        self.variable_values.get(index.get(name)?).copied()
+    }
+
+    /// Iterate over the names and values of the variables, in the order they have been added.
+    pub fn named_values(&self) -> impl Iterator<Item = (&str, f64)> + '_ {
+        self.variable_names
+            .iter()
+            .map(String::as_str)
+            .zip(self.variable_values.iter().copied())
+    }
+
+    /// Iterate over the names and values of the variables whose absolute value is greater
+    /// than `tolerance`.
+    pub fn nonzero_values(&self, tolerance: f64) -> impl Iterator<Item = (&str, f64)> + '_ {
+        self.named_values()
+            .filter(move |(_, value)| value.abs() > tolerance)
+    }
+
+    /// Write the names and values of the variables as CSV, with a `name,value` header.
+    pub fn write_csv<W: Write>(&self, mut writer: W) -> Result<()> {
+        writeln!(writer, "name,value")?;
+        for (name, value) in self.named_values() {
+            writeln!(writer, "{},{value}", csv_field(name))?;
+        }
+        Ok(writer.flush()?)
+    }
+
+    /// Write the objective value and the names and values of the variables as JSON, i.e. the
+    /// serialized `SolutionSnapshot` of the solution:
+    /// `{"objective_value":1.5,"variables":[["x",0.5],["y",1.0]]}`.
+    #[cfg(feature = "serde")]
+    pub fn write_json<W: Write>(&self, mut writer: W) -> Result<()> {
+        let snapshot = self.snapshot(&self.variable_names)?;
+        serde_json::to_writer(&mut writer, &snapshot).map_err(std::io::Error::from)?;
+        Ok(writer.flush()?)
+    }
+
+    /// Values of the objectives of a multi-objective problem, in the order they have been added.
+    pub fn objective_values(&self) -> &[f64] {
+        &self.objective_values
+    }
+
+    /// Value of an objective of a multi-objective problem in the solution.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the objective does not belong to the problem this solution comes from,
+    /// see `try_multi_objective_value`.
+    pub fn multi_objective_value(&self, o: ObjectiveId) -> f64 {
+        self.try_multi_objective_value(o)
+            .unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Value of an objective of a multi-objective problem in the solution, or an
+    /// `errors::Input` error if the objective does not belong to the problem this solution
+    /// comes from.
+    pub fn try_multi_objective_value(&self, o: ObjectiveId) -> Result<f64> {
+        self.objective_values
+            .get(o.index)
+            .copied()
+            .filter(|_| self.problem == o.problem)
+            .ok_or_else(|| errors::Input::ForeignObjective { index: o.index }.into())
+    }
+
+    /// The messages emitted by CPLEX during the solve, if log capture was enabled on the problem.
+    pub fn solve_log(&self) -> Option<&[LogMessage]> {
+        self.solve_log.as_deref()
+    }
+
+    /// Pair the values of the solution with the names of the variables, given in the order
+    /// the variables have been added to the problem.
+    pub fn snapshot<S>(&self, names: &[S]) -> Result<SolutionSnapshot>
+    where
+        S: AsRef<str>,
+    {
+        if names.len() != self.variable_values.len() {
+            return Err(errors::Input::from_message(format!(
+                "Expected {} variable names, got {}",
+                self.variable_values.len(),
+                names.len()
+            ))
+            .into());
+        }
+        Ok(SolutionSnapshot {
+            objective_value: self.objective_value,
+            variables: names
+                .iter()
+                .map(|n| n.as_ref().to_owned())
+                .zip(self.variable_values.iter().copied())
+                .collect(),
+        })
+    }
+
+    pub(crate) fn bind(self, problem: ProblemToken) -> Self {
+        Self {
+            problem,
+            model: None,
+            ..self
+        }
+    }
+
+    #[cfg(feature = "cplex")]
+    pub(crate) fn problem(&self) -> ProblemToken {
+        self.problem
+    }
+
+    /// Whether the solution comes from the problem or model with the given token, or from a
+    /// problem loaded from that model.
+    pub(crate) fn comes_from(&self, token: ProblemToken) -> bool {
+        self.problem == token || self.model.is_some_and(|m| m.token == token)
+    }
+
+    pub fn stats(&self) -> &SolveStats {
+        &self.stats
+    }
+}
+
+/// Quote a CSV field if it contains a separator, a quote or a line break
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_owned()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn solution() -> Solution {
+        Solution::new(ProblemToken::new(), vec![0.5, 0.0, 2.0], 1.5).with_names(
+            vec!["x".to_owned(), "y".to_owned(), "z, \"w\"".to_owned()],
+            vec!["c1".to_owned()],
+        )
+    }
+
+    #[test]
+    fn named_values() {
+        let solution = solution();
+        assert_eq!(solution.value_by_name("y"), Some(0.0));
+        assert_eq!(solution.value_by_name("w"), None);
+        assert_eq!(
+            solution.nonzero_values(1e-9).collect::<Vec<_>>(),
+            vec![("x", 0.5), ("z, \"w\"", 2.0)]
+        );
+        assert_eq!(solution.value_by_name("z, \"w\""), Some(2.0));
+        assert_eq!(solution.constraint_names(), &["c1".to_owned()]);
+    }
+
+    #[test]
+    fn write_solution() {
+        let solution = solution();
+
+        let mut csv = vec![];
+        solution.write_csv(&mut csv).unwrap();
+        assert_eq!(
+            String::from_utf8(csv).unwrap(),
+            "name,value\nx,0.5\ny,0\n\"z, \"\"w\"\"\",2\n"
+        );
+    }
+
+    #[test]
+    fn multi_objective_values() {
+        let problem = ProblemToken::new();
+        let solution = Solution::new(problem, vec![0.5], 1.5).with_objective_values(vec![1.5, 2.0]);
+        let objective = |index| ObjectiveId { index, problem };
+
+        assert_eq!(solution.multi_objective_value(objective(1)), 2.0);
+        assert!(matches!(
+            solution.try_multi_objective_value(objective(2)),
+            Err(errors::Error::Input(errors::Input::ForeignObjective {
+                index: 2
+            }))
+        ));
+        let foreign = ObjectiveId {
+            index: 0,
+            problem: ProblemToken::new(),
+        };
+        assert!(matches!(
+            solution.try_multi_objective_value(foreign),
+            Err(errors::Error::Input(errors::Input::ForeignObjective {
+                index: 0
+            }))
+        ));
+    }
+
+    #[test]
+    fn model_ids() {
+        let model = ProblemToken::new();
+        let problem = ProblemToken::new();
+        let solution = Solution::new(problem, vec![0.5, 1.0], 1.5).with_model(Some(ModelIds {
+            token: model,
+            variables: 1,
+            constraints: 0,
+        }));
+        let variable = |index, problem| VariableId { index, problem };
+
+        assert_eq!(solution.variable_value(variable(0, model)), 0.5);
+        assert_eq!(solution.variable_value(variable(1, problem)), 1.0);
+        assert!(matches!(
+            solution.try_variable_value(variable(1, model)),
+            Err(errors::Error::Input(errors::Input::ForeignVariable {
+                index: 1
+            }))
+        ));
+        assert!(solution.comes_from(model));
+        assert!(!solution.clone().bind(problem).comes_from(model));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn write_json() {
+        let mut json = vec![];
+        solution().write_json(&mut json).unwrap();
+        assert_eq!(
+            String::from_utf8(json).unwrap(),
+            r#"{"objective_value":1.5,"variables":[["x",0.5],["y",0.0],["z, \"w\"",2.0]]}"#
+        );
+
+        let unnamed = Solution::new(ProblemToken::new(), vec![0.5], 1.5);
+        assert!(unnamed.write_json(vec![]).is_err());
     }
 }
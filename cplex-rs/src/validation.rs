@@ -0,0 +1,194 @@
+//! Validation of the variables and constraints before they are passed to CPLEX, so that
+//! invalid values are reported with a descriptive `errors::Input` error rather than a CPLEX
+//! status code.
+
+use std::fmt::Display;
+
+use crate::{
+    constants::INFINITY,
+    errors::{self, InvalidValue, Result},
+    Constraint, ConstraintType, Variable, VariableId, VariableType,
+};
+
+/// Validate a variable about to be added to a problem at position `index`.
+pub(crate) fn validate_variable(index: usize, var: &Variable) -> Result<()> {
+    check_variable(var).map_err(|reason| {
+        errors::Input::Variable {
+            index,
+            name: var.name().to_owned(),
+            reason,
+        }
+        .into()
+    })
+}
+
+/// Validate a value given to the variable at position `index` after its creation, e.g. its
+/// coefficient in an objective or its value in a MIP start.
+#[cfg(feature = "cplex")]
+pub(crate) fn validate_value(index: usize, var: &Variable, field: &str, value: f64) -> Result<()> {
+    check_finite(field, value).map_err(|reason| {
+        errors::Input::Variable {
+            index,
+            name: var.name().to_owned(),
+            reason,
+        }
+        .into()
+    })
+}
+
+/// Validate a constraint about to be added to a problem at position `index`.
+///
+/// `is_known` tells whether a variable id belongs to the problem.
+pub(crate) fn validate_constraint<F>(
+    index: usize,
+    constraint: &Constraint,
+    is_known: F,
+) -> Result<()>
+where
+    F: Fn(VariableId) -> bool,
+{
+    if let Some(&(var, _)) = constraint.weights().iter().find(|&&(v, _)| !is_known(v)) {
+        return Err(errors::Input::ForeignVariable {
+            index: var.into_inner(),
+        }
+        .into());
+    }
+    check_constraint(constraint).map_err(|reason| {
+        errors::Input::Constraint {
+            index,
+            name: constraint.name().map(str::to_owned),
+            reason,
+        }
+        .into()
+    })
+}
+
+fn check_variable(var: &Variable) -> std::result::Result<(), InvalidValue> {
+    check_finite("objective coefficient", var.weight())?;
+    check_finite("lower bound", var.lower_bound())?;
+    check_finite("upper bound", var.upper_bound())?;
+
+    let (lower, upper) = (var.lower_bound(), var.upper_bound());
+    if lower > upper {
+        return Err(InvalidValue::BoundsOutOfOrder { lower, upper });
+    }
+    if matches!(var.type_(), VariableType::Binary) && (lower < 0.0 || upper > 1.0) {
+        return Err(InvalidValue::BinaryBounds { lower, upper });
+    }
+    Ok(())
+}
+
+fn check_constraint(constraint: &Constraint) -> std::result::Result<(), InvalidValue> {
+    check_finite("right hand side", constraint.rhs())?;
+    if constraint.type_() == ConstraintType::Ranged && constraint.range().is_none() {
+        return Err(InvalidValue::MissingRange);
+    }
+    if let Some(range) = constraint.range() {
+        check_finite("range", range)?;
+        if range < 0.0 {
+            return Err(InvalidValue::BoundsOutOfOrder {
+                lower: constraint.rhs(),
+                upper: constraint.rhs() + range,
+            });
+        }
+    }
+    for &(var, weight) in constraint.weights() {
+        check_coefficient(var.into_inner(), weight)?;
+    }
+    Ok(())
+}
+
+/// Validate the coefficient of variable `variable` in the constraint at position `index`,
+/// for constraints added in bulk from a sparse matrix.
+#[cfg(feature = "cplex")]
+pub(crate) fn validate_coefficient(index: usize, variable: usize, value: f64) -> Result<()> {
+    check_coefficient(variable, value).map_err(|reason| {
+        errors::Input::Constraint {
+            index,
+            name: None,
+            reason,
+        }
+        .into()
+    })
+}
+
+fn check_coefficient(variable: usize, value: f64) -> std::result::Result<(), InvalidValue> {
+    check_finite(format_args!("coefficient of variable {variable}"), value)
+}
+
+fn check_finite(field: impl Display, value: f64) -> std::result::Result<(), InvalidValue> {
+    if value.is_nan() {
+        Err(InvalidValue::NaN {
+            field: field.to_string(),
+        })
+    } else if value.abs() > INFINITY {
+        Err(InvalidValue::BeyondInfinity {
+            field: field.to_string(),
+            value,
+        })
+    } else {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn invalid_variables() {
+        let valid = Variable::new(VariableType::Continuous, 1.0, -INFINITY, INFINITY, "x");
+        assert!(check_variable(&valid).is_ok());
+
+        let nan = Variable::new(VariableType::Continuous, f64::NAN, 0.0, 1.0, "x");
+        assert!(matches!(
+            check_variable(&nan),
+            Err(InvalidValue::NaN { field }) if field == "objective coefficient"
+        ));
+
+        let infinite = Variable::new(VariableType::Continuous, 1.0, 0.0, f64::INFINITY, "x");
+        assert!(matches!(
+            check_variable(&infinite),
+            Err(InvalidValue::BeyondInfinity { .. })
+        ));
+
+        let reversed = Variable::new(VariableType::Integer, 1.0, 2.0, 1.0, "x");
+        assert_eq!(
+            check_variable(&reversed),
+            Err(InvalidValue::BoundsOutOfOrder {
+                lower: 2.0,
+                upper: 1.0
+            })
+        );
+
+        let binary = Variable::new(VariableType::Binary, 1.0, 0.0, 2.0, "x");
+        assert_eq!(
+            check_variable(&binary),
+            Err(InvalidValue::BinaryBounds {
+                lower: 0.0,
+                upper: 2.0
+            })
+        );
+    }
+
+    #[test]
+    fn invalid_constraints() {
+        let nan_rhs = Constraint::new(ConstraintType::Eq, f64::NAN, None, vec![]);
+        assert!(matches!(
+            check_constraint(&nan_rhs),
+            Err(InvalidValue::NaN { field }) if field == "right hand side"
+        ));
+
+        let no_range = Constraint::new(ConstraintType::Ranged, 1.0, None, vec![]);
+        assert_eq!(check_constraint(&no_range), Err(InvalidValue::MissingRange));
+
+        let reversed = Constraint::new_ranged(2.0, 1.0, None, vec![]);
+        assert_eq!(
+            check_constraint(&reversed),
+            Err(InvalidValue::BoundsOutOfOrder {
+                lower: 2.0,
+                upper: 1.0
+            })
+        );
+    }
+}
@@ -1,6 +1,8 @@
+#[cfg(feature = "cplex")]
 use ffi::{CPX_BINARY, CPX_CONTINUOUS, CPX_INTEGER, CPX_SEMICONT, CPX_SEMIINT};
 
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum VariableType {
     Continuous,
     Binary,
@@ -9,6 +11,7 @@ pub enum VariableType {
     SemiInteger,
 }
 
+#[cfg(feature = "cplex")]
 impl VariableType {
     pub(crate) fn into_raw(self) -> u8 {
         match self {
@@ -19,9 +22,21 @@ impl VariableType {
             VariableType::SemiInteger => CPX_SEMIINT,
         }
     }
+
+    pub(crate) fn from_raw(raw: u8) -> Option<Self> {
+        match raw {
+            CPX_CONTINUOUS => Some(VariableType::Continuous),
+            CPX_BINARY => Some(VariableType::Binary),
+            CPX_INTEGER => Some(VariableType::Integer),
+            CPX_SEMICONT => Some(VariableType::SemiContinuous),
+            CPX_SEMIINT => Some(VariableType::SemiInteger),
+            _ => None,
+        }
+    }
 }
 
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Variable {
     type_: VariableType,
     weight: f64,